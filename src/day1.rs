@@ -1,4 +1,4 @@
-use day_setup::Utils;
+use day_setup::{AssertionError, Utils};
 
 use crate::utils::day_setup;
 
@@ -7,12 +7,14 @@ use crate::utils::day_setup;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///  If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(function, part_num, day_num)
-    Utils::run_part(part1, 1, 1, Some(1462));
-    Utils::run_part(part2, 2, 1, Some(1497));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(function, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 1, Some(1462))?;
+    Utils::run_part(part2, 2, 1, Some(1497))?;
+
+    Ok(())
 }
 
 /// Solves part 1 of the Day 1 puzzle.
@@ -24,17 +26,8 @@ pub fn run() {
 ///
 /// # Returns
 /// The count of measurements that are larger than the previous measurement.
-fn part1(read_file: Vec<u16>) -> u64 {
-    let mut depth = u16::MAX;
-    let mut count = 0;
-    for i in read_file {
-        if depth < i {
-            count += 1;
-        }
-        depth = i;
-    }
-
-    count
+fn part1(read_file: Vec<i32>) -> u64 {
+    count_increases(&read_file, 1)
 }
 
 /// Solves part 2 of the Day 1 puzzle.
@@ -46,15 +39,46 @@ fn part1(read_file: Vec<u16>) -> u64 {
 ///
 /// # Returns
 /// The count of sums that are larger than the previous sum.
-fn part2(read_file: Vec<i16>) -> u64 {
-    let mut depth = i16::MAX;
-    let mut count = 0;
-    for window in read_file.windows(3) {
-        let n: i16 = window.iter().sum();
-        if depth < n {
-            count += 1;
-        }
-        depth = n;
+fn part2(read_file: Vec<i32>) -> u64 {
+    count_increases(&read_file, 3)
+}
+
+/// Counts the number of times the sum of a sliding window of measurements increases from
+/// the sum of the previous window.
+///
+/// # Arguments
+/// * `data` - The depth measurements.
+/// * `window` - The number of measurements summed in each window.
+///
+/// # Returns
+/// The count of window sums that are larger than the previous sum.
+fn count_increases(data: &[i32], window: usize) -> u64 {
+    let sums = data
+        .windows(window)
+        .map(|window| window.iter().sum::<i32>())
+        .collect::<Vec<_>>();
+
+    sums.windows(2).filter(|pair| pair[0] < pair[1]).count() as u64
+}
+
+#[cfg(test)]
+mod depth_tests {
+    use super::*;
+
+    const SAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_count_increases() {
+        assert_eq!(count_increases(&SAMPLE, 1), 7);
+        assert_eq!(count_increases(&SAMPLE, 3), 5);
+    }
+
+    #[test]
+    fn test_count_increases_without_sentinel() {
+        assert_eq!(
+            count_increases(&[u16::MAX as i32, u16::MAX as i32 + 1], 1),
+            1
+        );
+        assert_eq!(count_increases(&[1, 2], 3), 0);
     }
-    count
 }
@@ -1,4 +1,4 @@
-use day_setup::Utils;
+use day_setup::{AssertionError, Utils};
 use std::str::FromStr;
 
 use crate::utils::day_setup;
@@ -8,64 +8,28 @@ use crate::utils::day_setup;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part(part1, 1, 10, Some(318081));
-    Utils::run_part(part2, 2, 10, Some(4361305341));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 10, Some(318081))?;
+    Utils::run_part(part2, 2, 10, Some(4361305341))?;
+
+    Ok(())
 }
 
 fn part1(program: Vec<NavSubSystem>) -> u64 {
-    let mut map_count = [
-        (Instruction::CloseAngle, 0),
-        (Instruction::CloseCurly, 0),
-        (Instruction::CloseParen, 0),
-        (Instruction::CloseSquare, 0),
-    ];
-
-    for nav_system in program {
-        if let (Some(instruction), _) = nav_system.is_corrupted() {
-            match instruction {
-                Instruction::CloseAngle => map_count[0].1 += 1,
-                Instruction::CloseCurly => map_count[1].1 += 1,
-                Instruction::CloseParen => map_count[2].1 += 1,
-                Instruction::CloseSquare => map_count[3].1 += 1,
-                _ => panic!("Invalid error instruction: {:?}", instruction),
-            }
-        }
-    }
-
-    map_count
+    program
         .iter()
-        .map(|(k, v)| k.get_closing_points() as u64 * *v)
+        .filter_map(NavSubSystem::first_illegal)
+        .map(|instruction| instruction.get_closing_points() as u64)
         .sum()
 }
 
 fn part2(program: Vec<NavSubSystem>) -> u64 {
     let mut values = program
         .iter()
-        .filter_map(|nav_system| {
-            if let (None, stack) = nav_system.is_corrupted() {
-                const MULTIPLIER: u64 = 5;
-                Some(NavSubSystem::fix_corrupted(&stack).into_iter().fold(
-                    0u64,
-                    |mut acc, instruction| {
-                        acc *= MULTIPLIER;
-                        acc += match instruction {
-                            Instruction::CloseParen => 1,
-                            Instruction::CloseSquare => 2,
-                            Instruction::CloseCurly => 3,
-                            Instruction::CloseAngle => 4,
-                            _ => panic!("Invalid instruction: {:?}", instruction),
-                        };
-                        acc
-                    },
-                ))
-            } else {
-                None
-            }
-        })
+        .filter_map(NavSubSystem::autocomplete_score)
         .collect::<Vec<_>>();
 
     values.sort();
@@ -97,6 +61,29 @@ impl NavSubSystem {
         (None, stack)
     }
 
+    /// Returns the first closing instruction that does not match the most recently opened
+    /// chunk, or `None` if the line is not corrupted.
+    fn first_illegal(&self) -> Option<Instruction> {
+        self.is_corrupted().0
+    }
+
+    /// Returns the score of the instructions needed to close every chunk left open, or
+    /// `None` if the line is corrupted.
+    fn autocomplete_score(&self) -> Option<u64> {
+        const MULTIPLIER: u64 = 5;
+
+        match self.is_corrupted() {
+            (None, stack) => Some(
+                NavSubSystem::fix_corrupted(&stack)
+                    .into_iter()
+                    .fold(0, |acc, instruction| {
+                        acc * MULTIPLIER + instruction.get_autocomplete_points() as u64
+                    }),
+            ),
+            (Some(_), _) => None,
+        }
+    }
+
     fn fix_corrupted(instruction_stack: &[Instruction]) -> Vec<Instruction> {
         instruction_stack
             .iter()
@@ -175,6 +162,16 @@ impl Instruction {
             _ => panic!("Invalid instruction: {:?}", self),
         }
     }
+
+    fn get_autocomplete_points(&self) -> u8 {
+        match self {
+            Instruction::CloseParen => 1,
+            Instruction::CloseSquare => 2,
+            Instruction::CloseCurly => 3,
+            Instruction::CloseAngle => 4,
+            _ => panic!("Invalid instruction: {:?}", self),
+        }
+    }
 }
 
 impl TryFrom<char> for Instruction {
@@ -194,3 +191,51 @@ impl TryFrom<char> for Instruction {
         }
     }
 }
+
+#[cfg(test)]
+mod nav_sub_system_tests {
+    use super::*;
+
+    const SAMPLE: [&str; 10] = [
+        "[({(<(())[]>[[{[]{<()<>>",
+        "[(()[<>])]({[<{<<[]>>(",
+        "{([(<{}[<>[]}>{[]{[(<()>",
+        "(((({<>}<{<{<>}{[]{[]{}",
+        "[[<[([]))<([[{}[[()]]]",
+        "[{[{({}]{}}([{[{{{}}([]",
+        "{<[[]]>}<{[{[{[]{()[[[]",
+        "[<(<(<(<{}))><([]([]()",
+        "<{([([[(<>()){}]>(<<{{",
+        "<{([{{}}[<[[[<>{}]]]>[]]",
+    ];
+
+    fn sample() -> Vec<NavSubSystem> {
+        SAMPLE.iter().map(|line| line.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_first_illegal() {
+        let illegal = sample()
+            .iter()
+            .map(NavSubSystem::first_illegal)
+            .collect::<Vec<_>>();
+
+        assert_eq!(illegal[2], Some(Instruction::CloseCurly));
+        assert_eq!(illegal[4], Some(Instruction::CloseParen));
+        assert_eq!(illegal[0], None);
+        assert_eq!(part1(sample()), 26397);
+    }
+
+    #[test]
+    fn test_autocomplete_score() {
+        let scores = sample()
+            .iter()
+            .map(NavSubSystem::autocomplete_score)
+            .collect::<Vec<_>>();
+
+        assert_eq!(scores[0], Some(288957));
+        assert_eq!(scores[9], Some(294));
+        assert_eq!(scores[2], None);
+        assert_eq!(part2(sample()), 288957);
+    }
+}
@@ -1,8 +1,7 @@
-use crate::utils::coordinate_system::direction::FullDirection;
 use crate::utils::coordinate_system::Coordinate;
 use crate::utils::day_setup;
 use crate::utils::grid::sized_grid::SizedGrid;
-use day_setup::Utils;
+use day_setup::{AssertionError, Utils};
 use std::fmt::Debug;
 
 /// Runs the Advent of Code puzzles for [Current Day](https://adventofcode.com/2021/day/11).
@@ -10,15 +9,18 @@ use std::fmt::Debug;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part_single(part1, 1, 11, Some(1729));
-    Utils::run_part_single(part2, 2, 11, Some(237));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part_single(part1, 1, 11, Some(1729))?;
+    Utils::run_part_single(part2, 2, 11, Some(237))?;
+
+    Ok(())
 }
 const GRID_SIZE: usize = 10;
-fn part1(mut octopus_grid: OctopusGrid) -> u64 {
+
+fn part1(mut octopus_grid: OctopusGrid<GRID_SIZE>) -> u64 {
     for _ in 0..100 {
         octopus_grid.raise_energy_levels();
         octopus_grid.process_flashes();
@@ -27,7 +29,7 @@ fn part1(mut octopus_grid: OctopusGrid) -> u64 {
     octopus_grid.num_flashes
 }
 
-fn part2(mut octopus_grid: OctopusGrid) -> u64 {
+fn part2(mut octopus_grid: OctopusGrid<GRID_SIZE>) -> u64 {
     for i in 0.. {
         octopus_grid.raise_energy_levels();
         let all_flashing = octopus_grid.process_flashes();
@@ -38,20 +40,21 @@ fn part2(mut octopus_grid: OctopusGrid) -> u64 {
     unreachable!("The octopus grid should have all grids flashing by now.")
 }
 
+/// An `N` x `N` grid of octopuses.
 #[derive(Debug)]
-struct OctopusGrid {
-    grid: SizedGrid<EnergyLevel, GRID_SIZE, GRID_SIZE>,
+struct OctopusGrid<const N: usize> {
+    grid: SizedGrid<Octopus, N, N>,
     curr_flashes: Vec<Coordinate>,
     num_flashes: u64,
 }
 
-impl OctopusGrid {
+impl<const N: usize> OctopusGrid<N> {
     /// Handles the flashes of the octopuses in the grid.
     ///
     /// This function processes the current flashes in the grid by iterating through
     /// the positions in `curr_flashes`. For each position, it checks all adjacent
     /// positions in all directions. If an adjacent position contains an octopus that
-    /// is not already flashing and its energy level is raised to the flash level,
+    /// has not flashed yet this step and its energy level is raised past the flash level,
     /// it is added to the `curr_flashes` queue. The total number of flashes is updated
     /// accordingly.
     ///
@@ -60,10 +63,9 @@ impl OctopusGrid {
     pub(crate) fn process_flashes(&mut self) -> bool {
         let mut num_flashes = self.curr_flashes.len();
         while let Some(curr_position) = self.curr_flashes.pop() {
-            for dir in FullDirection::full_direction_list() {
-                let new_position = curr_position + dir;
+            for new_position in curr_position.neighbours_8() {
                 if let Some(e) = self.grid.get_mut(&new_position) {
-                    if *e != EnergyLevel::Flash && e.raise_energy() {
+                    if e.raise_energy() {
                         self.curr_flashes.push(new_position);
                         self.num_flashes += 1;
                         num_flashes += 1;
@@ -71,19 +73,21 @@ impl OctopusGrid {
                 }
             }
         }
-        num_flashes == GRID_SIZE * GRID_SIZE
+        num_flashes == N * N
     }
 
     /// Raises the energy levels of all octopuses in the grid.
     ///
-    /// This function iterates through each octopus in the grid and raises its energy level.
-    /// If an octopus's energy level reaches the flash level, its position is added to the
+    /// This function starts a new step, so octopuses that flashed in the previous step may
+    /// flash again, and raises the energy level of each octopus in the grid.
+    /// If an octopus's energy level passes the flash level, its position is added to the
     /// `curr_flashes` queue, and the total number of flashes is incremented.
     pub(crate) fn raise_energy_levels(&mut self) {
         self.curr_flashes.clear();
         for row in self.grid.iter_mut() {
-            for (position, energy) in row {
-                if energy.raise_energy() {
+            for (position, octopus) in row {
+                octopus.flashed = false;
+                if octopus.raise_energy() {
                     self.curr_flashes.push(position);
                     self.num_flashes += 1;
                 }
@@ -92,101 +96,135 @@ impl OctopusGrid {
     }
 }
 
-impl From<Vec<String>> for OctopusGrid {
+impl<const N: usize> From<Vec<String>> for OctopusGrid<N> {
     fn from(value: Vec<String>) -> Self {
-        assert_eq!(
-            value.len(),
-            GRID_SIZE,
-            "Invalid number of rows: {}",
-            value.len()
-        );
+        assert_eq!(value.len(), N, "Invalid number of rows: {}", value.len());
         assert!(
-            value.iter().all(|row| row.len() == GRID_SIZE),
+            value.iter().all(|row| row.len() == N),
             "Invalid number of columns"
         );
 
-        let mut grid = [[EnergyLevel::Flash; GRID_SIZE]; GRID_SIZE];
+        let mut grid = [[Octopus::default(); N]; N];
         for (i, row) in value.iter().enumerate() {
             for (j, e) in row.chars().enumerate() {
-                grid[i][j] = EnergyLevel::from(e);
+                grid[i][j] = Octopus::from(e);
             }
         }
         Self {
             grid: SizedGrid::new(grid),
             num_flashes: 0,
-            curr_flashes: Vec::with_capacity(100),
+            curr_flashes: Vec::with_capacity(N * N),
         }
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum EnergyLevel {
-    /// FLASH MODE
-    Flash = 0,
-    One = 1,
-    Two = 2,
-    Three = 3,
-    Four = 4,
-    Five = 5,
-    Six = 6,
-    Seven = 7,
-    Eight = 8,
-    Nine = 9,
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+struct Octopus {
+    energy: u8,
+    /// Whether the octopus has flashed during the current step, after which its energy
+    /// stays at 0 until the next step.
+    flashed: bool,
 }
 
-impl EnergyLevel {
+impl Octopus {
+    /// Highest energy level an octopus can hold without flashing.
+    const MAX_ENERGY: u8 = 9;
+
     /// Raises the energy level of the octopus by one.
     ///
-    /// This function increments the energy level of the octopus by one and updates
-    /// the current energy level. If the new energy level reaches the `FLASH` level,
-    /// the function returns `true`.
+    /// This function increments the energy level of the octopus by one, unless it has
+    /// already flashed this step. If the new energy level passes `MAX_ENERGY`, the octopus
+    /// flashes and its energy level drops back to 0.
     ///
     /// # Returns
-    /// `true` if the new energy level is `FLASH`, otherwise `false`.
+    /// `true` if the octopus flashed, otherwise `false`.
     pub(crate) fn raise_energy(&mut self) -> bool {
-        let new_level = EnergyLevel::from(*self as u8 + 1);
-        *self = new_level;
-        new_level == EnergyLevel::Flash
+        if self.flashed {
+            return false;
+        }
+
+        self.energy += 1;
+        if self.energy > Self::MAX_ENERGY {
+            self.energy = 0;
+            self.flashed = true;
+        }
+        self.flashed
     }
 }
 
-impl From<char> for EnergyLevel {
+impl From<char> for Octopus {
     fn from(value: char) -> Self {
-        match value {
-            '1' => Self::One,
-            '2' => Self::Two,
-            '3' => Self::Three,
-            '4' => Self::Four,
-            '5' => Self::Five,
-            '6' => Self::Six,
-            '7' => Self::Seven,
-            '8' => Self::Eight,
-            '9' => Self::Nine,
-            _ => panic!("Invalid energy level: {}", value),
+        match value.to_digit(10) {
+            Some(energy) => Self {
+                energy: energy as u8,
+                flashed: false,
+            },
+            None => panic!("Invalid energy level: {}", value),
         }
     }
 }
 
-impl From<u8> for EnergyLevel {
-    fn from(value: u8) -> Self {
-        match value {
-            1 => Self::One,
-            2 => Self::Two,
-            3 => Self::Three,
-            4 => Self::Four,
-            5 => Self::Five,
-            6 => Self::Six,
-            7 => Self::Seven,
-            8 => Self::Eight,
-            9 => Self::Nine,
-            10 => Self::Flash,
-            _ => panic!("Invalid energy level: {}", value),
-        }
+impl Debug for Octopus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.energy)
     }
 }
 
-impl Debug for EnergyLevel {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", *self as u8)
+#[cfg(test)]
+mod octopus_grid_tests {
+    use super::*;
+
+    const SAMPLE: [&str; 10] = [
+        "5483143223",
+        "2745854711",
+        "5264556173",
+        "6141336146",
+        "6357385478",
+        "4167524645",
+        "2176841721",
+        "6882881134",
+        "4846848554",
+        "5283751526",
+    ];
+
+    fn sample() -> OctopusGrid<10> {
+        OctopusGrid::from(SAMPLE.map(String::from).to_vec())
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(part1(sample()), 1656);
+        assert_eq!(part2(sample()), 195);
+    }
+
+    #[test]
+    fn test_small_grid() {
+        let mut octopus_grid: OctopusGrid<5> = OctopusGrid::from(
+            ["11111", "19991", "19191", "19991", "11111"]
+                .map(String::from)
+                .to_vec(),
+        );
+
+        octopus_grid.raise_energy_levels();
+        assert!(!octopus_grid.process_flashes());
+        assert_eq!(octopus_grid.num_flashes, 9);
+    }
+
+    #[test]
+    fn test_flashed_octopus_is_not_raised() {
+        let mut octopus = Octopus::from('9');
+        assert!(octopus.raise_energy());
+        assert_eq!(octopus.energy, 0);
+
+        // Neighbouring flashes in the same step leave it at 0
+        assert!(!octopus.raise_energy());
+        assert!(!octopus.raise_energy());
+        assert_eq!(octopus.energy, 0);
+
+        octopus.flashed = false;
+        assert!(!octopus.raise_energy());
+        assert_eq!(octopus.energy, 1);
+
+        assert_eq!(Octopus::from('0').energy, 0);
     }
 }
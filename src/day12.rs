@@ -1,268 +1,36 @@
-use crate::utils::day_setup::Utils;
+use crate::utils::day_setup::{AssertionError, Utils};
 use crate::utils::graph::{Graph, Neighbours, NodePtr, Relationship};
 use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
-use std::sync::mpsc::{Receiver, Sender};
 
 /// Runs the Advent of Code puzzles for [Current Day](https://adventofcode.com/2021/day/12).
 ///
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part_single(part1, 1, 12, Some(4691));
-    Utils::run_part_single(part2, 2, 12, Some(140718));
-}
-
-fn part1(cave_map: CaveMap) -> u64 {
-    let mut small_caves_stack: Vec<NodePtr> = Vec::with_capacity(cave_map.map.len());
-    distinct_path_once(&cave_map, &cave_map.start, &mut small_caves_stack)
-}
-
-fn part2(cave_map: CaveMap) -> usize {
-    let mut path_builder = PathsBuilder::new();
-
-    std::thread::scope(|scope| {
-        cave_map
-            .get_nodes()
-            .iter()
-            .filter(|node| matches!(node, Cave::Small(_)))
-            .map(|cave| (cave, path_builder.new_path()))
-            .for_each(|(repeat_cave, path)| {
-                scope.spawn(|| {
-                    let mut path = path;
-                    distinct_path_with_options(
-                        &cave_map,
-                        &cave_map.start,
-                        &mut path,
-                        (repeat_cave, 2),
-                    );
-                });
-            });
-    });
-
-    path_builder.build()
-}
-
-fn distinct_path_once(
-    cave_map: &CaveMap,
-    curr_index: &NodePtr,
-    small_caves_stack: &mut Vec<NodePtr>,
-) -> u64 {
-    if *curr_index == cave_map.end {
-        return 1;
-    }
-
-    let mut result = 0;
-    for (curr_node_index, _) in cave_map.neighbours(curr_index) {
-        // Cannot move though the start state & cannot pass through small caves more than once
-        if small_caves_stack.contains(curr_node_index) || *curr_node_index == cave_map.start {
-            continue;
-        }
-
-        if matches!(cave_map.map.get(curr_node_index), Cave::Small(_)) {
-            small_caves_stack.push(curr_node_index.clone());
-        }
-
-        result += distinct_path_once(cave_map, curr_node_index, small_caves_stack);
-    }
-
-    if matches!(cave_map.map.get(curr_index), Cave::Small(_)) {
-        small_caves_stack.pop();
-    }
-
-    result
-}
-
-fn distinct_path_with_options(
-    cave_map: &CaveMap,
-    curr_index: &NodePtr,
-    path: &mut Path,
-    (repeat_node, mut count): (&Cave, i32),
-) {
-    path.add_to_path(cave_map.get_node_data(curr_index).clone());
-
-    if *curr_index == cave_map.end {
-        path.send();
-        return;
-    }
-
-    for (curr_node_index, _) in cave_map.neighbours(curr_index) {
-        // Cannot move though the start state & cannot pass through small caves more than once
-        let current_cave = cave_map.get_node_data(curr_node_index);
-
-        if repeat_node == current_cave && count != 0 {
-            count -= 1;
-            // Continue on as you haven't visited the node twice
-        } else if path.contains(curr_node_index) || *curr_node_index == cave_map.start {
-            continue;
-        }
-
-        if matches!(cave_map.map.get(curr_node_index), Cave::Small(_)) {
-            path.add_to_visited(curr_node_index.clone());
-        }
-
-        distinct_path_with_options(cave_map, curr_node_index, path, (repeat_node, count));
-        path.pop_path();
-
-        if repeat_node == current_cave {
-            count += 1;
-        }
-    }
-
-    if matches!(cave_map.map.get(curr_index), Cave::Small(_)) {
-        path.remove_from_visited(curr_index);
-    }
-}
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part_single(part1, 1, 12, Some(4691))?;
+    Utils::run_part_single(part2, 2, 12, Some(140718))?;
 
-/// A builder for managing and storing paths in the cave system.
-///
-/// This struct is responsible for creating new paths, storing the final paths,
-/// and sending the completed paths through a channel.
-struct PathsBuilder {
-    /// A set of final paths represented as strings.
-    final_paths: HashSet<String>,
-    /// An optional sender channel to send the completed paths.
-    tx: Option<Sender<Vec<String>>>,
-    /// A receiver channel to receive the completed paths.
-    rx: Receiver<Vec<String>>,
-}
-
-/// Represents a path in the cave system.
-///
-/// This struct is used to build and store a path through the cave system,
-/// sending the completed path through a channel when finished.
-struct Path {
-    /// The sender channel to send the completed path.
-    tx: Sender<Vec<String>>,
-    /// The current path being built.
-    path: Vec<String>,
-    /// A set of visited nodes in the current path.
-    visited: HashSet<NodePtr>,
+    Ok(())
 }
 
-impl Path {
-    /// Adds a cave to the current path.
-    ///
-    /// # Arguments
-    /// * `cave` - The cave to add to the path.
-    fn add_to_path(&mut self, cave: Cave) {
-        self.path.push(format!("{:?}", cave));
-    }
-
-    /// Removes a node from the set of visited nodes.
-    ///
-    /// # Arguments
-    /// * `node_ptr` - The node to remove from the visited set.
-    fn remove_from_visited(&mut self, node_ptr: &NodePtr) {
-        self.visited.remove(node_ptr);
-    }
-
-    /// Checks if a node is in the set of visited nodes.
-    ///
-    /// # Arguments
-    /// * `node_ptr` - The node to check.
-    ///
-    /// # Returns
-    /// `true` if the node is in the visited set, `false` otherwise.
-    fn contains(&mut self, node_ptr: &NodePtr) -> bool {
-        self.visited.contains(node_ptr)
-    }
-
-    /// Removes the last cave from the current path.
-    ///
-    /// # Panics
-    /// Panics if the path is empty.
-    fn pop_path(&mut self) {
-        assert!(self.path.pop().is_some());
-    }
-
-    /// Adds a node to the set of visited nodes.
-    ///
-    /// # Arguments
-    /// * `node_ptr` - The node to add to the visited set.
-    fn add_to_visited(&mut self, node_ptr: NodePtr) {
-        self.visited.insert(node_ptr);
-    }
-
-    /// Sends the completed path through the channel.
-    ///
-    /// # Panics
-    /// Panics if the channel fails to send the path.
-    fn send(&self) {
-        self.tx
-            .send(self.path.clone())
-            .expect("Failed to send path to build");
-    }
+fn part1(cave_map: CaveMap) -> usize {
+    // Only big caves may be passed through more than once
+    cave_map
+        .map
+        .all_simple_paths(cave_map.start.clone(), cave_map.end.clone(), |cave, _| {
+            matches!(cave, Cave::Big(_))
+        })
+        .len()
 }
 
-impl PathsBuilder {
-    /// Creates a new `PathsBuilder`.
-    ///
-    /// # Returns
-    /// A new instance of `PathsBuilder`.
-    fn new() -> PathsBuilder {
-        let (tx, rx) = std::sync::mpsc::channel();
-        Self {
-            final_paths: HashSet::new(),
-            rx,
-            tx: Some(tx),
-        }
-    }
-
-    /// Creates a new `Path`.
-    ///
-    /// # Returns
-    /// A new instance of `Path`.
-    ///
-    /// # Panics
-    /// Panics if the channel sender no longer exists.
-    fn new_path(&self) -> Path {
-        Path {
-            tx: self
-                .tx
-                .clone()
-                .expect("Cannot create path as the Channel Sender no longer exists")
-                .clone(),
-            path: vec![],
-            visited: HashSet::new(),
-        }
-    }
-
-    /// Returns the number of final paths.
-    ///
-    /// # Returns
-    /// The number of final paths.
-    fn count(&self) -> usize {
-        self.final_paths.len()
-    }
-
-    /// Builds the final paths by collecting them from the receiver channel.
-    ///
-    /// # Returns
-    /// The number of final paths.
-    fn build(&mut self) -> usize {
-        drop(self.tx.take());
-        for path in self.rx.iter() {
-            self.final_paths
-                .insert(path.iter().fold(String::new(), |acc, x| acc + x));
-        }
-
-        self.count()
-    }
-}
-
-impl Debug for PathsBuilder {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Paths to End {{")?;
-        for path in self.final_paths.iter() {
-            writeln!(f, "\t{:?}", path)?;
-        }
-        write!(f, "}}")
-    }
+fn part2(cave_map: CaveMap) -> usize {
+    // A single small cave may be visited twice
+    cave_map.count_paths(&cave_map.start, &mut HashSet::new(), false)
 }
 
 #[derive(Debug)]
@@ -277,12 +45,49 @@ impl CaveMap {
         self.map.neighbours_iter(curr_index)
     }
 
-    fn get_nodes(&self) -> Vec<&Cave> {
-        self.map.nodes()
-    }
+    /// Counts the paths from `curr_index` to the end that never return to the start and enter every
+    /// small cave at most once, except for a single small cave that may be entered twice as long as
+    /// `used_double` is `false`.
+    ///
+    /// # Arguments
+    /// * `curr_index` - The cave the path is currently in.
+    /// * `visited` - The small caves already on the path.
+    /// * `used_double` - Whether a small cave has already been entered twice on the path.
+    ///
+    /// # Returns
+    /// The number of distinct paths to the end.
+    fn count_paths(
+        &self,
+        curr_index: &NodePtr,
+        visited: &mut HashSet<NodePtr>,
+        used_double: bool,
+    ) -> usize {
+        if *curr_index == self.end {
+            return 1;
+        }
 
-    fn get_node_data(&self, node_ptr: &NodePtr) -> &Cave {
-        self.map.get(node_ptr)
+        let mut count = 0;
+        for (next_index, _) in self.neighbours(curr_index) {
+            if *next_index == self.start {
+                continue;
+            }
+
+            match self.map.get(next_index) {
+                Cave::Big(_) => count += self.count_paths(next_index, visited, used_double),
+                _ if visited.contains(next_index) => {
+                    if !used_double {
+                        count += self.count_paths(next_index, visited, true);
+                    }
+                }
+                _ => {
+                    visited.insert(next_index.clone());
+                    count += self.count_paths(next_index, visited, used_double);
+                    visited.remove(next_index);
+                }
+            }
+        }
+
+        count
     }
 }
 
@@ -327,27 +132,136 @@ impl From<String> for Cave {
     }
 }
 
-impl From<Vec<String>> for CaveMap {
-    fn from(value: Vec<String>) -> Self {
-        let points = value
-            .into_iter()
+impl CaveMap {
+    /// Builds the cave map from lines of `from-to` connections.
+    ///
+    /// # Errors
+    ///   If a line is not a `from-to` pair, if a cave is connected to itself, or if the map has no
+    ///   `start` or `end` cave.
+    fn parse(input: &[String]) -> Result<Self, String> {
+        let points = input
+            .iter()
             .map(|points| {
-                let (from, to) = points.split_once('-').unwrap();
-                (
+                let (from, to) = points
+                    .split_once('-')
+                    .ok_or_else(|| format!("Expected a 'from-to' connection, found '{points}'"))?;
+                if from.is_empty() || to.is_empty() {
+                    return Err(format!("Missing a cave name in '{points}'"));
+                }
+                if from == to {
+                    return Err(format!("Cave '{from}' cannot connect to itself"));
+                }
+
+                Ok((
                     Cave::from(from.to_string()),
                     Cave::from(to.to_string()),
                     Relationship::BiDirectional {
                         a_to_b: (),
                         b_to_a: (),
                     },
-                )
+                ))
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
         let graph = Graph::from(points);
-        CaveMap {
-            start: graph.find_node_index(|data| data == &Cave::Start).unwrap(),
-            end: graph.find_node_index(|data| data == &Cave::End).unwrap(),
+
+        Ok(CaveMap {
+            start: graph
+                .find_node_index(|data| data == &Cave::Start)
+                .ok_or("Missing start")?,
+            end: graph
+                .find_node_index(|data| data == &Cave::End)
+                .ok_or("Missing end")?,
             map: graph,
-        }
+        })
+    }
+}
+
+impl From<Vec<String>> for CaveMap {
+    fn from(value: Vec<String>) -> Self {
+        CaveMap::parse(&value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+#[cfg(test)]
+mod cave_map_tests {
+    use super::*;
+
+    fn cave_map(input: &str) -> CaveMap {
+        CaveMap::from(input.lines().map(String::from).collect::<Vec<_>>())
+    }
+
+    const SMALL: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    const LARGER: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sg
+kj-HN
+kj-dc";
+
+    #[test]
+    fn test_samples() {
+        assert_eq!(part1(cave_map(SMALL)), 10);
+        assert_eq!(part1(cave_map(LARGER)), 19);
+        assert_eq!(part2(cave_map(SMALL)), 36);
+        assert_eq!(part2(cave_map(LARGER)), 103);
+    }
+
+    #[test]
+    fn test_ambiguous_cave_names() {
+        // start,a,bc,end and start,ab,c,end both read "abc" once their names are glued together
+        let ambiguous = "start-a\na-bc\nbc-end\nstart-ab\nab-c\nc-end";
+        assert_eq!(part1(cave_map(ambiguous)), 2);
+        assert_eq!(part2(cave_map(ambiguous)), 2);
+
+        // A small and a big cave that only differ by case are still different caves
+        let cased = "start-b\nb-end\nstart-B\nB-end\nb-B";
+        assert_eq!(part1(cave_map(cased)), 5);
+        assert_eq!(part2(cave_map(cased)), 9);
+    }
+
+    #[test]
+    fn test_invalid_maps() {
+        let parse =
+            |input: &str| CaveMap::parse(&input.lines().map(String::from).collect::<Vec<_>>());
+
+        assert_eq!(
+            parse("start-A\nA-b").unwrap_err(),
+            "Missing end".to_string()
+        );
+        assert_eq!(
+            parse("A-b\nb-end").unwrap_err(),
+            "Missing start".to_string()
+        );
+        assert_eq!(
+            parse("start-start\nstart-end").unwrap_err(),
+            "Cave 'start' cannot connect to itself".to_string()
+        );
+        assert_eq!(
+            parse("start-b\nb-b\nb-end").unwrap_err(),
+            "Cave 'b' cannot connect to itself".to_string()
+        );
+        assert_eq!(
+            parse("start-\nstart-end").unwrap_err(),
+            "Missing a cave name in 'start-'".to_string()
+        );
+        assert_eq!(
+            parse("start-end\n-end").unwrap_err(),
+            "Missing a cave name in '-end'".to_string()
+        );
+        assert!(parse("start-b\nb end").is_err());
+        assert!(parse(SMALL).is_ok());
     }
 }
@@ -1,6 +1,7 @@
 use crate::utils::coordinate_system::Coordinate;
-use crate::utils::day_setup::Utils;
+use crate::utils::day_setup::{AssertionError, Utils};
 use crate::utils::grid::unsized_grid::UnsizedGrid;
+use crate::utils::grid::Grid;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::fmt;
@@ -12,12 +13,14 @@ use std::str::FromStr;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part_single(part1, 1, 13, Some(669));
-    Utils::run_part_single(part2, 2, 13, Some("UEFZCUCJ"));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part_single(part1, 1, 13, Some(669))?;
+    Utils::run_part_single(part2, 2, 13, Some(String::from("UEFZCUCJ")))?;
+
+    Ok(())
 }
 
 fn part1(mut origami: Origami) -> u64 {
@@ -32,24 +35,42 @@ fn part1(mut origami: Origami) -> u64 {
         .len() as u64
 }
 
-fn part2(mut origami: Origami) -> &'static str {
-    let mut repeat_points = HashSet::new();
-    for fold_instruction in origami.fold_instructions.iter() {
-        for idx in (0..origami.dot_coordinates.len()).rev() {
-            let coordinate = &mut origami.dot_coordinates[idx];
-            fold_instruction.fold(coordinate);
-            if !repeat_points.insert(*coordinate) {
-                let _ = origami.dot_coordinates.swap_remove(idx);
-            }
-        }
-        repeat_points.clear()
-    }
+fn part2(mut origami: Origami) -> String {
+    origami.fold_all();
+    origami.ocr().unwrap()
+}
 
-    origami.visualize();
+/// Width of a letter drawn by the folded dots, excluding the blank column between letters.
+const LETTER_WIDTH: usize = 4;
+/// Height of a letter drawn by the folded dots.
+const LETTER_HEIGHT: usize = 6;
 
-    "UEFZCUCJ"
-}
+/// The capital letters known to appear in the folded dots, drawn row by row with `#` for a dot.
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...#....#.#..#...#...#."),
+    ('Z', "####...#..#..#..#...####"),
+];
 
+/// Transparent paper covered in dots.
+///
+/// Dots are stored with `i` as the row (the `y` of the input) and `j` as the column (the `x`
+/// of the input), so they can be drawn directly onto a grid.
 #[derive(Debug)]
 struct Origami {
     dot_coordinates: Vec<Coordinate>,
@@ -65,28 +86,82 @@ impl Origami {
         }
     }
 
-    fn visualize(&self) {
-        let transposed_points: Vec<Coordinate> = self
+    /// Applies every fold instruction in order, dropping the dots that end up overlapping.
+    fn fold_all(&mut self) {
+        let mut repeat_points = HashSet::new();
+        for fold_instruction in self.fold_instructions.iter() {
+            for idx in (0..self.dot_coordinates.len()).rev() {
+                let coordinate = &mut self.dot_coordinates[idx];
+                fold_instruction.fold(coordinate);
+                if !repeat_points.insert(*coordinate) {
+                    let _ = self.dot_coordinates.swap_remove(idx);
+                }
+            }
+            repeat_points.clear()
+        }
+    }
+
+    /// Reads the capital letters drawn by the dots, each `LETTER_WIDTH` wide and
+    /// `LETTER_HEIGHT` tall with a blank column between letters.
+    ///
+    /// # Errors
+    /// If a letter does not match any glyph in `FONT`.
+    fn ocr(&self) -> Result<String, String> {
+        let dots = self
             .dot_coordinates
             .iter()
-            .map(Coordinate::transpose)
-            .collect();
-        let (max_x, max_y) = transposed_points
-            .iter()
-            .fold((0, 0), |(x, y), point| (x.max(point.i), y.max(point.j)));
+            .map(|point| (point.j as usize, point.i as usize))
+            .collect::<HashSet<_>>();
+        let width = dots.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let num_letters = width.div_ceil(LETTER_WIDTH + 1);
+
+        (0..num_letters)
+            .map(|letter| {
+                let left = letter * (LETTER_WIDTH + 1);
+                let glyph = (0..LETTER_HEIGHT)
+                    .flat_map(|y| (left..left + LETTER_WIDTH).map(move |x| (x, y)))
+                    .map(|point| if dots.contains(&point) { '#' } else { '.' })
+                    .collect::<String>();
+
+                FONT.iter()
+                    .find(|(_, pattern)| *pattern == glyph)
+                    .map(|&(c, _)| c)
+                    .ok_or_else(|| format!("Unrecognised letter {} with dots {glyph}", letter + 1))
+            })
+            .collect()
+    }
+
+    /// Renders the paper after the first `num_folds` fold instructions as rows of `#` for
+    /// a dot and `.` for an empty spot.
+    fn render_after(&self, num_folds: usize) -> String {
+        let (mut height, mut width) = self.dot_coordinates.iter().fold((0, 0), |(h, w), point| {
+            (h.max(point.i as usize + 1), w.max(point.j as usize + 1))
+        });
 
-        let mut grid = UnsizedGrid::new_with_size(max_x as usize + 1, max_y as usize + 1, ' ');
-        for point in &transposed_points {
-            *grid.get_mut(point).unwrap() = '#';
+        let folds = &self.fold_instructions[..num_folds];
+        for fold_instruction in folds {
+            match *fold_instruction {
+                FoldInstruction::Horizontal(fold_line) => height = fold_line as usize,
+                FoldInstruction::Vertical(fold_line) => width = fold_line as usize,
+            }
+        }
+
+        let mut paper = Origami::new(self.dot_coordinates.clone(), folds.into());
+        paper.fold_all();
+
+        let mut grid = UnsizedGrid::new_with_size(height, width, false);
+        for point in &paper.dot_coordinates {
+            *grid.get_mut(point).unwrap() = true;
         }
 
-        println!("{:?}", grid);
+        grid.render_with(|_, &dot| if dot { '#' } else { '.' })
     }
 }
 
+#[derive(Clone, Copy)]
 enum FoldInstruction {
-    Horizontal(u16), // y axis
-    Vertical(u16),   // x axis
+    Horizontal(u16), // fold along y, a row
+    Vertical(u16),   // fold along x, a column
 }
 
 impl FoldInstruction {
@@ -139,7 +214,9 @@ impl From<Vec<String>> for Origami {
                 continue;
             }
             if read_points {
-                points.push(line.parse().unwrap());
+                // x,y => row y, column x
+                let (x, y) = line.split_once(',').expect("Dot is not of the form 'x,y'");
+                points.push(Coordinate::new(y.parse().unwrap(), x.parse().unwrap()));
             } else {
                 fold_instructions.push(line.parse().unwrap());
             }
@@ -161,8 +238,8 @@ impl FromStr for FoldInstruction {
         } {
             let fold_coord = amount.parse().map_err(|e: ParseIntError| e.to_string())?;
             match axis {
-                "x" => Ok(FoldInstruction::Horizontal(fold_coord)),
-                "y" => Ok(FoldInstruction::Vertical(fold_coord)),
+                "y" => Ok(FoldInstruction::Horizontal(fold_coord)),
+                "x" => Ok(FoldInstruction::Vertical(fold_coord)),
                 _ => Err(format!("Invalid fold axis: {}", axis)),
             }
         } else {
@@ -171,6 +248,13 @@ impl FromStr for FoldInstruction {
     }
 }
 
+impl fmt::Display for Origami {
+    /// Renders the paper once every fold instruction has been applied.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render_after(self.fold_instructions.len()))
+    }
+}
+
 impl fmt::Debug for FoldInstruction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -179,3 +263,110 @@ impl fmt::Debug for FoldInstruction {
         }
     }
 }
+
+#[cfg(test)]
+mod origami_tests {
+    use super::*;
+
+    /// Builds an origami without fold instructions from rows of `#` dots.
+    fn from_art(rows: &[&str]) -> Origami {
+        let dots = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(x, _)| Coordinate::new(y as i32, x as i32))
+            })
+            .collect();
+
+        Origami::new(dots, Box::new([]))
+    }
+
+    const SAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    fn sample() -> Origami {
+        Origami::from(SAMPLE.lines().map(String::from).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_render_first_fold() {
+        let rendered = sample().render_after(1);
+        assert_eq!(rendered.matches('#').count(), 17);
+        assert_eq!(part1(sample()), 17);
+        assert_eq!(
+            rendered,
+            "\
+#.##..#..#.
+#...#......
+......#...#
+#...#......
+.#.#..#.###
+...........
+..........."
+        );
+    }
+
+    #[test]
+    fn test_dots_render_upright() {
+        // x is the column and y is the row
+        let origami = Origami::from(["3,0", "0,2", "1,1"].map(String::from).to_vec());
+        assert_eq!(origami.to_string(), "...#\n.#..\n#...");
+
+        let (dot_row, dot_col) = (origami.dot_coordinates[0].i, origami.dot_coordinates[0].j);
+        assert_eq!((dot_row, dot_col), (0, 3));
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(
+            sample().to_string(),
+            "\
+#####
+#...#
+#...#
+#...#
+#####
+.....
+....."
+        );
+    }
+
+    #[test]
+    fn test_ocr() {
+        let origami = from_art(&[
+            "#..#.####.#....#.....##.",
+            "#..#.#....#....#....#..#",
+            "####.###..#....#....#..#",
+            "#..#.#....#....#....#..#",
+            "#..#.#....#....#....#..#",
+            "#..#.####.####.####..##.",
+        ]);
+        assert_eq!(origami.ocr(), Ok("HELLO".to_string()));
+
+        let unknown = from_art(&["#"]);
+        assert!(unknown.ocr().is_err());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::utils::day_setup::Utils;
+use crate::utils::counter::Counter;
+use crate::utils::day_setup::{AssertionError, Utils};
 use std::collections::HashMap;
 use std::slice::Iter;
 
@@ -7,18 +8,18 @@ use std::slice::Iter;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part_single(part1, 1, 14, Some(3118));
-    Utils::run_part_single(part2, 2, 14, Some(4332887448171));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part_single(part1, 1, 14, Some(3118))?;
+    Utils::run_part_single(part2, 2, 14, Some(4332887448171))?;
+
+    Ok(())
 }
 
 fn part1(mut polymer_formula: PolymerFormula) -> u64 {
-    const COUNT: u8 = 10;
-
-    simulate::<{ COUNT }>(&mut polymer_formula);
+    polymer_formula.simulate(10);
 
     let (min, max) = polymer_formula.polymer_template.min_max_occurrence();
 
@@ -26,45 +27,41 @@ fn part1(mut polymer_formula: PolymerFormula) -> u64 {
 }
 
 fn part2(mut polymer_formula: PolymerFormula) -> u64 {
-    const COUNT: u8 = 40;
-
-    simulate::<{ COUNT }>(&mut polymer_formula);
+    polymer_formula.simulate(40);
 
     let (min, max) = polymer_formula.polymer_template.min_max_occurrence();
 
     max - min
 }
 
-fn simulate<const COUNT: u8>(polymer_formula: &mut PolymerFormula) {
-    let mut new_points = Vec::new();
-    let mut points_to_remove = Vec::new();
-    for _ in 0..COUNT {
-        for ((a, b), count) in polymer_formula.polymer_template.template.iter() {
-            if let Some(new) =
-                PolymerFormula::get_replacement(&polymer_formula.insertion_rules, (*a, *b))
-            {
-                polymer_formula.polymer_template.element_count[(new as u8 - b'A') as usize] +=
-                    count;
-                points_to_remove.push((*a, *b));
-                new_points.push(([(*a, new), (new, *b)], *count));
-            }
-        }
-
-        polymer_formula
-            .polymer_template
-            .update_points(&new_points, &points_to_remove);
-
-        new_points.clear();
-        points_to_remove.clear();
-    }
-}
-
 struct PolymerFormula {
     polymer_template: PolymerTemplate,
     insertion_rules: HashMap<(char, char), char>,
 }
 
 impl PolymerFormula {
+    /// Applies the pair insertion rules to the polymer `steps` times.
+    fn simulate(&mut self, steps: usize) {
+        let mut new_points = Vec::new();
+        let mut points_to_remove = Vec::new();
+        for _ in 0..steps {
+            for ((a, b), count) in self.polymer_template.template.iter() {
+                if let Some(new) = PolymerFormula::get_replacement(&self.insertion_rules, (*a, *b))
+                {
+                    self.polymer_template.element_count.add(new, *count);
+                    points_to_remove.push((*a, *b));
+                    new_points.push(([(*a, new), (new, *b)], *count));
+                }
+            }
+
+            self.polymer_template
+                .update_points(&new_points, &points_to_remove);
+
+            new_points.clear();
+            points_to_remove.clear();
+        }
+    }
+
     pub fn get_replacement(
         insertion_rules: &HashMap<(char, char), char>,
         (first, second): (char, char),
@@ -90,7 +87,7 @@ impl PolymerFormula {
 
 struct PolymerTemplate {
     template: HashMap<(char, char), u64>,
-    element_count: [u64; 26],
+    element_count: Counter<char>,
 }
 
 impl PolymerTemplate {
@@ -113,29 +110,18 @@ impl PolymerTemplate {
     }
 
     fn min_max_occurrence(&self) -> (u64, u64) {
-        let min = self
-            .element_count
-            .iter()
-            .filter(|&&x| x != 0)
-            .min()
-            .unwrap();
-
-        let max = self.element_count.iter().max().unwrap();
-
-        (*min, *max)
+        self.element_count
+            .min_max()
+            .expect("The polymer has no elements")
     }
 }
 
 impl From<Vec<String>> for PolymerFormula {
     fn from(input: Vec<String>) -> Self {
-        let mut count = [0; 26];
-
         let mut iter = input.iter();
         let binding = iter.next().unwrap().chars().collect::<Vec<char>>();
 
-        binding
-            .iter()
-            .for_each(|&c| count[(c as u8 - b'A') as usize] += 1);
+        let count = binding.iter().copied().collect::<Counter<_>>();
 
         let mut polymer_template = HashMap::new();
 
@@ -157,3 +143,60 @@ impl From<Vec<String>> for PolymerFormula {
         }
     }
 }
+
+#[cfg(test)]
+mod polymer_tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    fn sample() -> PolymerFormula {
+        PolymerFormula::from(SAMPLE.lines().map(String::from).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_simulate() {
+        assert_eq!(part1(sample()), 1588);
+        assert_eq!(part2(sample()), 2188189693529);
+
+        let mut polymer_formula = sample();
+        polymer_formula.simulate(0);
+        assert_eq!(
+            polymer_formula.polymer_template.min_max_occurrence(),
+            (1, 2)
+        );
+    }
+
+    #[test]
+    fn test_any_element_symbols() {
+        let input = ["a1", "", "a1 -> a", "aa -> 1"].map(String::from).to_vec();
+        let mut polymer_formula = PolymerFormula::from(input);
+
+        // a1 => aa1 => a1aa1
+        polymer_formula.simulate(2);
+        assert_eq!(polymer_formula.polymer_template.element_count.get(&'a'), 3);
+        assert_eq!(polymer_formula.polymer_template.element_count.get(&'1'), 2);
+        assert_eq!(
+            polymer_formula.polymer_template.min_max_occurrence(),
+            (2, 3)
+        );
+    }
+}
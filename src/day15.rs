@@ -1,7 +1,6 @@
 // Import necessary modules and types from the crate
-use crate::utils::coordinate_system::direction::Direction;
 use crate::utils::coordinate_system::Coordinate;
-use crate::utils::day_setup::Utils;
+use crate::utils::day_setup::{AssertionError, Utils};
 use crate::utils::grid::unsized_grid::UnsizedGrid;
 use crate::utils::grid::Grid;
 use std::cmp::Reverse; // For using Reverse in the BinaryHeap
@@ -12,11 +11,13 @@ use std::collections::BinaryHeap; // For the priority queue implementation
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    Utils::run_part_single(part1, 1, 15, Some(498));
-    Utils::run_part_single(part2, 2, 15, Some(2901));
+pub fn run() -> Result<(), AssertionError> {
+    Utils::run_part_single(part1, 1, 15, Some(498))?;
+    Utils::run_part_single(part2, 2, 15, Some(2901))?;
+
+    Ok(())
 }
 
 // Define type aliases for clarity
@@ -30,7 +31,7 @@ fn part1(mut risk_map: RiskMap) -> MinRisk {
 
 // Function for part 2, expanding the grid and calculating the lowest risk path
 fn part2(risk_map: RiskMap) -> MinRisk {
-    risk_map.expand_5x().lowest_risk() // Expands the grid and calculates lowest risk
+    risk_map.expand(5).lowest_risk() // Expands the grid and calculates lowest risk
 }
 
 // Struct representing the risk map, which contains the grid and the end coordinate
@@ -48,40 +49,34 @@ impl RiskMap {
         Self { grid, end_coord } // Return the new RiskMap instance
     }
 
-    // Expands the risk map 5 times in both dimensions
-    fn expand_5x(self) -> Self {
-        // Create a new grid that is 5 times the size of the original
-        let mut new_grid = UnsizedGrid::new_with_size(
-            self.grid.num_rows() * 5,
-            self.grid.num_cols() * 5,
-            (0, MinRisk::MAX), // Initialize with default risk and maximum minimum risk
-        );
-
+    // Expands the risk map `factor` times in both dimensions
+    fn expand(self, factor: usize) -> Self {
         // Get original grid dimensions
         let original_width = self.grid.num_cols();
         let original_height = self.grid.num_rows();
 
-        // Iterate over the new grid to populate risks
-        for row in new_grid.iter_mut() {
-            for (position, (risk, _)) in row {
+        // Create a new grid that is `factor` times the size of the original
+        let new_grid = UnsizedGrid::from_fn(
+            original_height * factor,
+            original_width * factor,
+            |position| {
                 // Calculate base position in the original grid
-                let base_x = position.i % original_width as i32; // Horizontal index
-                let base_y = position.j % original_height as i32; // Vertical index
-                let target_x = position.i / original_width as i32; // Horizontal expansion index
-                let target_y = position.j / original_height as i32; // Vertical expansion index
+                let base_i = position.i % original_height as i32; // Row index
+                let base_j = position.j % original_width as i32; // Column index
+                let tile_i = position.i / original_height as i32; // Vertical expansion index
+                let tile_j = position.j / original_width as i32; // Horizontal expansion index
 
                 // Get the risk from the original grid
-                let base_risk = self.grid.get(&Coordinate::new(base_x, base_y)).unwrap().0;
+                let base_risk = self.grid.get(&Coordinate::new(base_i, base_j)).unwrap().0;
 
-                // Calculate new risk value considering expansion
-                *risk = base_risk + target_x as u8 + target_y as u8;
+                // Calculate new risk value considering expansion, wrapping from 9 back to 1
+                // as many times as needed so risk values remain between 1 and 9
+                let increment = (tile_i + tile_j) as usize;
+                let risk = ((base_risk as usize - 1 + increment) % 9 + 1) as Risk;
 
-                // Wrap risk value if it exceeds 9
-                if *risk > 9 {
-                    *risk -= 9; // Ensures risk values remain between 1 and 9
-                }
-            }
-        }
+                (risk, MinRisk::MAX) // Start with the maximum minimum risk
+            },
+        );
 
         // Return the new expanded RiskMap
         RiskMap::new(new_grid)
@@ -99,9 +94,8 @@ impl RiskMap {
                 return acc_risk; // Return the accumulated risk if reached the end
             }
 
-            // Iterate through possible directions from the current coordinate
-            for direction in Direction::direction_list() {
-                let new_coord = coord + direction; // Calculate new coordinate
+            // Iterate through the neighbouring coordinates of the current coordinate
+            for new_coord in coord.neighbours() {
                 if let Some((risk, min_risk)) = self.grid.get_mut(&new_coord) {
                     // Calculate new risk by adding the current risk value
                     let new_risk = acc_risk + *risk as u16;
@@ -117,26 +111,133 @@ impl RiskMap {
 
         unreachable!("There is always a path to the bottom-right corner"); // Safety guarantee
     }
+
+    // Calculates the lowest risk path using A*, guided by the Manhattan distance to the end.
+    // The heuristic never overestimates since every step costs at least 1 risk.
+    #[allow(dead_code)]
+    fn lowest_risk_astar(&mut self) -> MinRisk {
+        let end_coord = self.end_coord;
+        let heuristic = |coord: Coordinate| (end_coord - coord).manhattan_distance() as MinRisk;
+
+        // Priority queue ordered by the estimated total risk, then the accumulated risk
+        let mut heap = BinaryHeap::<Reverse<(MinRisk, MinRisk, Coordinate)>>::new();
+        let start = Coordinate::new(0, 0);
+        heap.push(Reverse((heuristic(start), 0, start)));
+
+        while let Some(Reverse((_, acc_risk, coord))) = heap.pop() {
+            if coord == end_coord {
+                return acc_risk;
+            }
+
+            for new_coord in coord.neighbours() {
+                if let Some((risk, min_risk)) = self.grid.get_mut(&new_coord) {
+                    let new_risk = acc_risk + *risk as u16;
+
+                    if new_risk < *min_risk {
+                        *min_risk = new_risk;
+                        heap.push(Reverse((
+                            new_risk + heuristic(new_coord),
+                            new_risk,
+                            new_coord,
+                        )));
+                    }
+                }
+            }
+        }
+
+        unreachable!("There is always a path to the bottom-right corner");
+    }
 }
 
 // Implementing conversion from a vector of strings to a RiskMap
 impl From<Vec<String>> for RiskMap {
     fn from(input: Vec<String>) -> Self {
-        // Create a grid from input strings
-        let grid = UnsizedGrid::from_box(
-            input
-                .iter()
-                .map(|row| {
-                    // Convert each character to its risk value and initialize min_risk
-                    row.chars()
-                        .map(|c| (c as u8 - b'0', MinRisk::MAX))
-                        .collect::<Vec<_>>() // Collect into a Vec
-                        .into_boxed_slice() // Convert to boxed slice
-                })
-                .collect::<Vec<Box<[_]>>>()
-                .into_boxed_slice(), // Collect into boxed slice of boxed slices
-        );
+        // Create a grid of risk values, initializing each min_risk to the maximum
+        let grid = UnsizedGrid::from_digit_lines(&input)
+            .expect("Invalid risk map")
+            .map(|_, &risk| (risk, MinRisk::MAX));
 
         RiskMap::new(grid) // Return a new RiskMap instance
     }
 }
+
+#[cfg(test)]
+mod risk_map_tests {
+    use super::*;
+
+    const SAMPLE: [&str; 10] = [
+        "1163751742",
+        "1381373672",
+        "2136511328",
+        "3694931569",
+        "7463417111",
+        "1319128137",
+        "1359912421",
+        "3125421639",
+        "1293138521",
+        "2311944581",
+    ];
+
+    fn sample() -> RiskMap {
+        RiskMap::from(SAMPLE.map(String::from).to_vec())
+    }
+
+    fn risk_at(risk_map: &RiskMap, i: i32, j: i32) -> Risk {
+        risk_map.grid.get(&Coordinate::new(i, j)).unwrap().0
+    }
+
+    #[test]
+    fn test_lowest_risk() {
+        assert_eq!(part1(sample()), 40);
+        assert_eq!(part2(sample()), 315);
+    }
+
+    #[test]
+    fn test_expand_identity() {
+        let expanded = sample().expand(1);
+        let original = sample();
+
+        assert_eq!(expanded.end_coord, original.end_coord);
+        let risks = |risk_map: &RiskMap| {
+            risk_map
+                .grid
+                .iter()
+                .flatten()
+                .map(|(_, &(risk, _))| risk)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(risks(&expanded), risks(&original));
+    }
+
+    #[test]
+    fn test_expand_wraps() {
+        // (3, 2) has a risk of 9, which wraps to 1 one tile down or right and 2 diagonally
+        let expanded = sample().expand(2);
+        assert_eq!(expanded.end_coord, Coordinate::new(19, 19));
+        assert_eq!(risk_at(&expanded, 3, 2), 9);
+        assert_eq!(risk_at(&expanded, 13, 2), 1);
+        assert_eq!(risk_at(&expanded, 13, 12), 2);
+
+        // The last tile of a 20x expansion adds 38 to the risk of 1 at (0, 0)
+        let expanded = sample().expand(20);
+        assert_eq!(risk_at(&expanded, 190, 190), 3);
+    }
+
+    #[test]
+    fn test_expand_non_square() {
+        let expanded = RiskMap::from(vec!["123".to_string(), "456".to_string()]).expand(2);
+        assert_eq!(expanded.end_coord, Coordinate::new(3, 5));
+
+        let last_row = (0..6).map(|j| risk_at(&expanded, 3, j)).collect::<Vec<_>>();
+        assert_eq!(last_row, [5, 6, 7, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        assert_eq!(sample().lowest_risk_astar(), sample().lowest_risk());
+        assert_eq!(
+            sample().expand(5).lowest_risk_astar(),
+            sample().expand(5).lowest_risk()
+        );
+    }
+}
@@ -1,291 +1,401 @@
-use crate::utils::day_setup::Utils;
-use std::ops::RangeInclusive;
+use crate::utils::day_setup::{AssertionError, Utils};
+use std::fmt;
 
 /// Runs the Advent of Code puzzles for [Current Day](https://adventofcode.com/2021/day/16).
 ///
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part(part1, 1, 16, Some(977));
-    Utils::run_part(part2, 2, 16, Some(101501020883));
-}
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part_raw(part1, 1, 16, Some(977))?;
+    Utils::run_part_raw(part2, 2, 16, Some(101501020883))?;
 
-struct PacketResult<'rest> {
-    version_number: u64,
-    rest: &'rest str,
+    Ok(())
 }
 
-fn part1(input: Vec<String>) -> u64 {
-    let binary_strings = hex_to_binary_strings(input.first().unwrap());
-    let mut packet = Packet {
-        bits: &binary_strings,
-        evaluated_expression: None,
-    };
-
-    packet.decode_version_number().version_number
+fn part1(input: String) -> u64 {
+    let mut reader = BitReader::from_hex(&input).expect("Invalid transmission");
+    Packet::parse(&mut reader).version_sum()
 }
 
-fn part2(input: Vec<String>) -> u64 {
-    let binary_strings = hex_to_binary_strings(input.first().unwrap());
-    let mut packet = Packet {
-        bits: &binary_strings,
-        evaluated_expression: None,
-    };
-
-    let _ = packet.decode_version_number();
-
-    packet.evaluated_expression.unwrap()
+fn part2(input: String) -> u64 {
+    let mut reader = BitReader::from_hex(&input).expect("Invalid transmission");
+    Packet::parse(&mut reader).evaluate()
 }
 
+/// Reads a hexadecimal transmission bit by bit, most significant bit first.
 #[derive(Debug)]
-struct Packet<'a> {
-    /// The bits representing the packet.
-    bits: &'a str,
-    /// The evaluated expression value of the packet, if any.
-    evaluated_expression: Option<u64>,
+struct BitReader {
+    /// The value of every hex digit of the transmission, in order.
+    nibbles: Vec<u8>,
+    /// The index of the next bit to be read.
+    position: usize,
 }
 
-impl<'a> Packet<'a> {
-    /// The range of bits representing the version number.
-    const VERSION_NUMBER: RangeInclusive<usize> = 0..=2;
-    /// The range of bits representing the type ID.
-    const TYPE_ID_RANGE: RangeInclusive<usize> = 3..=5;
-
-    /// Decodes the version number from the packet's bits.
+impl BitReader {
+    /// Creates a reader over the bits of a hexadecimal string.
     ///
-    /// The version number is located in the first three bits of the packet.
+    /// Surrounding whitespace is ignored and both upper and lowercase digits are accepted.
     ///
     /// # Returns
-    /// A `PacketResult` containing the version number and the remaining bits.
+    /// The `BitReader`, or an error naming the first character that is not a hex digit and its
+    /// position in the trimmed string.
+    fn from_hex(hex: &str) -> Result<Self, String> {
+        let nibbles = hex
+            .trim()
+            .chars()
+            .enumerate()
+            .map(|(position, char)| {
+                char.to_digit(16)
+                    .map(|nibble| nibble as u8)
+                    .ok_or_else(|| format!("Invalid hex digit {:?} at position {}", char, position))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            nibbles,
+            position: 0,
+        })
+    }
+
+    /// Reads the next `n` bits as a big-endian number.
     ///
     /// # Panics
-    /// Panics if the bits length is less than 6.
-    fn decode_version_number(&mut self) -> PacketResult<'a> {
-        let bits = self.bits;
-        assert!(bits.len() >= 6, "Bits too short: {}", bits);
-        let version_number = Self::binary_str_to_int(&bits[Self::VERSION_NUMBER]);
-        let type_id = Self::binary_str_to_int(&bits[Self::TYPE_ID_RANGE]);
-
-        if type_id == 4 {
-            // base case
-            let (evaluated_expression, rest) = Self::decode_literal(&bits[6..]);
-
-            self.evaluated_expression = Some(evaluated_expression);
-
-            PacketResult {
-                version_number,
-                rest,
-            }
-        } else {
-            let mut compute_fn =
-                Self::compute_from_type_id(type_id as usize, &mut self.evaluated_expression);
-
-            if bits.as_bytes()[6] as char == '0' {
-                let offset = 7 + 15;
-                let sub_packet_length = Self::binary_str_to_int(&bits[7..offset]);
-
-                let mut acc_version_number = version_number;
-                let bits = &bits[offset..];
-                let mut new_bits = &bits[..sub_packet_length as usize];
-
-                while !new_bits.is_empty() {
-                    let mut new_packet = Packet {
-                        bits: new_bits,
-                        evaluated_expression: None,
-                    };
-
-                    let PacketResult {
-                        version_number,
-                        rest,
-                    } = new_packet.decode_version_number();
-
-                    compute_fn(new_packet.evaluated_expression.unwrap());
-
-                    new_bits = rest;
-                    acc_version_number += version_number;
-                }
-
-                // Return the other packets that were not consumed in the fixed range
-                PacketResult {
-                    version_number: acc_version_number,
-                    rest: &bits[sub_packet_length as usize..],
-                }
-            } else {
-                let offset = 7 + 11;
-
-                let sub_packet_length = Self::binary_str_to_int(&bits[7..offset]);
-                let mut bits = &bits[offset..];
-                let mut acc_version_number = version_number;
-
-                for _ in 0..sub_packet_length {
-                    let mut new_packet = Packet {
-                        bits,
-                        evaluated_expression: None,
-                    };
+    /// Panics if `n` is larger than 64 or if fewer than `n` bits are left.
+    fn take(&mut self, n: usize) -> u64 {
+        assert!(n <= 64, "Cannot read {} bits into a u64", n);
+        assert!(
+            self.position + n <= self.nibbles.len() * 4,
+            "Cannot read {} bits at position {}: only {} bits in total",
+            n,
+            self.position,
+            self.nibbles.len() * 4
+        );
+
+        let value = (self.position..self.position + n).fold(0, |acc, bit| {
+            let nibble = self.nibbles[bit / 4];
+            (acc << 1) | ((nibble >> (3 - bit % 4)) & 1) as u64
+        });
+        self.position += n;
 
-                    let PacketResult {
-                        version_number,
-                        rest,
-                    } = new_packet.decode_version_number();
+        value
+    }
 
-                    compute_fn(new_packet.evaluated_expression.unwrap());
+    /// Returns the index of the next bit to be read.
+    fn position(&self) -> usize {
+        self.position
+    }
+}
 
-                    acc_version_number += version_number;
-                    bits = rest;
-                }
+/// A decoded packet of the transmission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Packet {
+    /// A packet holding a single number.
+    Literal { version: u8, value: u64 },
+    /// A packet applying the operation selected by its `type_id` to its sub-packets.
+    Operator {
+        version: u8,
+        type_id: u8,
+        children: Vec<Packet>,
+    },
+}
 
-                PacketResult {
-                    version_number: acc_version_number,
-                    rest: bits,
-                }
-            }
-        }
-    }
+impl Packet {
+    /// The number of bits of the version number.
+    const VERSION_BITS: usize = 3;
+    /// The number of bits of the type ID.
+    const TYPE_ID_BITS: usize = 3;
+    /// The type ID of a literal value packet.
+    const LITERAL_TYPE_ID: u8 = 4;
 
-    /// Returns a closure that modifies the accumulator based on the `type_id`.
+    /// Parses the packet starting at the reader's position, along with all of its sub-packets.
     ///
     /// # Arguments
-    /// * `type_id` - The type ID of the packet.
-    /// * `acc` - A mutable reference to an optional accumulator value.
-    ///
-    /// # Panics
-    /// Panics if the accumulator is not `None`.
+    /// * `reader` - The reader positioned at the start of the packet. It is left just after the
+    ///   packet, so the rest of the transmission can be read from it.
     ///
     /// # Returns
-    /// A closure that takes a `u64` value and modifies the accumulator.
-    fn compute_from_type_id(type_id: usize, acc: &mut Option<u64>) -> Box<dyn FnMut(u64) + '_> {
-        assert!(acc.is_none(), "Accumulator should be None");
-        match type_id {
-            0 => {
-                *acc = Some(0);
-                Box::new(|b: u64| {
-                    let acc_ref = acc.as_mut().unwrap();
-                    *acc_ref += b;
-                })
-            }
-            1 => {
-                *acc = Some(1);
-                Box::new(|b: u64| {
-                    let acc_ref = acc.as_mut().unwrap();
-                    *acc_ref *= b;
-                })
-            }
-            2 => {
-                *acc = Some(u64::MAX);
-                Box::new(|b: u64| {
-                    let acc_ref = acc.as_mut().unwrap();
-                    *acc_ref = (*acc_ref).min(b);
-                })
-            }
-            3 => {
-                *acc = Some(0);
-                Box::new(|b: u64| {
-                    let acc_ref = acc.as_mut().unwrap();
-                    *acc_ref = (*acc_ref).max(b);
-                })
-            }
-            5 => {
-                *acc = Some(u64::MAX);
-                Box::new(|b: u64| {
-                    let acc_ref = acc.as_mut().unwrap();
-                    if *acc_ref == u64::MAX {
-                        *acc_ref = b;
-                    } else if *acc_ref > b {
-                        *acc_ref = 1;
-                    } else {
-                        *acc_ref = 0;
-                    }
-                })
-            }
-            6 => {
-                *acc = Some(u64::MAX);
-                Box::new(|b: u64| {
-                    let acc_ref = acc.as_mut().unwrap();
-                    if *acc_ref == u64::MAX {
-                        *acc_ref = b;
-                    } else if *acc_ref < b {
-                        *acc_ref = 1;
-                    } else {
-                        *acc_ref = 0;
-                    }
-                })
+    /// The parsed `Packet`.
+    fn parse(reader: &mut BitReader) -> Packet {
+        let version = reader.take(Self::VERSION_BITS) as u8;
+        let type_id = reader.take(Self::TYPE_ID_BITS) as u8;
+
+        if type_id == Self::LITERAL_TYPE_ID {
+            return Packet::Literal {
+                version,
+                value: Self::decode_literal(reader),
+            };
+        }
+
+        let mut children = vec![];
+        if reader.take(1) == 0 {
+            // The next 15 bits are the total length in bits of the sub-packets
+            let sub_packet_length = reader.take(15) as usize;
+            let end = reader.position() + sub_packet_length;
+            while reader.position() < end {
+                children.push(Self::parse(reader));
             }
-            7 => {
-                *acc = Some(u64::MAX);
-                Box::new(|b: u64| {
-                    let acc_ref = acc.as_mut().unwrap();
-                    if *acc_ref == u64::MAX {
-                        *acc_ref = b;
-                    } else if *acc_ref == b {
-                        *acc_ref = 1;
-                    } else {
-                        *acc_ref = 0;
-                    }
-                })
+        } else {
+            // The next 11 bits are the number of sub-packets
+            for _ in 0..reader.take(11) {
+                children.push(Self::parse(reader));
             }
-            _ => unreachable!("Invalid type_id: {}", type_id),
+        }
+
+        Packet::Operator {
+            version,
+            type_id,
+            children,
         }
     }
 
-    /// Decodes a literal value from the packet's bits.
+    /// Decodes a literal value, made of groups of 5 bits where the first bit marks whether
+    /// another group follows.
     ///
     /// # Arguments
-    /// * `sub_bits` - A string slice representing the bits to decode.
+    /// * `reader` - The reader positioned at the first group.
     ///
     /// # Returns
-    /// A tuple containing the decoded literal value and the remaining bits.
-    fn decode_literal(mut sub_bits: &str) -> (u64, &str) {
-        let mut acc = String::with_capacity(sub_bits.len());
+    /// The decoded literal value.
+    fn decode_literal(reader: &mut BitReader) -> u64 {
+        let mut acc = 0;
         loop {
-            acc.push_str(&sub_bits[1..=4]);
+            let group = reader.take(5);
+            acc = (acc << 4) | (group & 0b1111);
 
-            if sub_bits.as_bytes()[0] == b'0' {
-                return (Self::binary_str_to_int(&acc), &sub_bits[5..]);
+            if group & 0b10000 == 0 {
+                return acc;
             }
+        }
+    }
 
-            sub_bits = &sub_bits[5..];
+    /// # Returns
+    /// The sum of the version numbers of this packet and all of its sub-packets.
+    fn version_sum(&self) -> u64 {
+        match self {
+            Packet::Literal { version, .. } => *version as u64,
+            Packet::Operator {
+                version, children, ..
+            } => *version as u64 + children.iter().map(Packet::version_sum).sum::<u64>(),
         }
     }
 
-    /// Converts a binary string to an integer.
-    ///
-    /// # Arguments
-    /// * `binary_string` - A string slice representing the binary string.
+    /// Evaluates the expression represented by this packet.
     ///
     /// # Returns
-    /// A `u64` value representing the integer.
-    fn binary_str_to_int(binary_string: &str) -> u64 {
-        u64::from_str_radix(binary_string, 2).unwrap()
+    /// The value of the expression.
+    ///
+    /// # Panics
+    /// Panics if a comparison does not have exactly two sub-packets or the type ID is unknown.
+    fn evaluate(&self) -> u64 {
+        let (type_id, children) = match self {
+            Packet::Literal { value, .. } => return *value,
+            Packet::Operator {
+                type_id, children, ..
+            } => (*type_id, children),
+        };
+
+        let mut values = children.iter().map(Packet::evaluate);
+        match type_id {
+            0 => values.sum(),
+            1 => values.product(),
+            2 => values.min().expect("Minimum of no packets"),
+            3 => values.max().expect("Maximum of no packets"),
+            5..=7 => {
+                assert_eq!(children.len(), 2, "Comparisons take exactly two packets");
+                let (a, b) = (values.next().unwrap(), values.next().unwrap());
+                let holds = match type_id {
+                    5 => a > b,
+                    6 => a < b,
+                    _ => a == b,
+                };
+                holds as u64
+            }
+            _ => unreachable!("Invalid type_id: {}", type_id),
+        }
+    }
+}
+
+impl fmt::Display for Packet {
+    /// Writes the packet as a nested expression, e.g. `sum(v1)(lit(v2)(1), lit(v0)(2))`, where
+    /// the version of every packet follows its name in parentheses.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Literal { version, value } => write!(f, "lit(v{})({})", version, value),
+            Packet::Operator {
+                version,
+                type_id,
+                children,
+            } => {
+                let name = match type_id {
+                    0 => "sum",
+                    1 => "product",
+                    2 => "min",
+                    3 => "max",
+                    5 => "gt",
+                    6 => "lt",
+                    7 => "eq",
+                    _ => "unknown",
+                };
+                write!(f, "{}(v{})(", name, version)?;
+                for (i, child) in children.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", child)?;
+                }
+                write!(f, ")")
+            }
+        }
     }
 }
 
-fn hex_to_binary_strings(hex: &str) -> String {
-    let mut bits = String::with_capacity(hex.len() * 4);
-    for char in hex.chars() {
-        bits.push_str(match char {
-            '0' => "0000",
-            '1' => "0001",
-            '2' => "0010",
-            '3' => "0011",
-            '4' => "0100",
-            '5' => "0101",
-            '6' => "0110",
-            '7' => "0111",
-            '8' => "1000",
-            '9' => "1001",
-            'A' => "1010",
-            'B' => "1011",
-            'C' => "1100",
-            'D' => "1101",
-            'E' => "1110",
-            'F' => "1111",
-            other => panic!("Unknown char {}", other),
+#[cfg(test)]
+mod packet_tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_reader() {
+        let mut reader = BitReader::from_hex("D2FE28").unwrap();
+        assert_eq!(reader.take(3), 6);
+        assert_eq!(reader.take(3), 4);
+        assert_eq!(reader.position(), 6);
+        assert_eq!(reader.take(15), 0b101111111000101);
+        assert_eq!(reader.position(), 21);
+        assert_eq!(reader.take(3), 0);
+    }
+
+    #[test]
+    fn test_version_sum() {
+        [
+            ("8A004A801A8002F478", 16),
+            ("620080001611562C8802118E34", 12),
+            ("C0015000016115A2E0802F182340", 23),
+            ("A0016C880162017C3686B18A3D4780", 31),
+        ]
+        .into_iter()
+        .for_each(|(hex, expected)| {
+            assert_eq!(part1(hex.to_string()), expected, "Version sum of {}", hex);
+        });
+    }
+
+    #[test]
+    fn test_evaluate() {
+        [
+            ("C200B40A82", 3),
+            ("04005AC33890", 54),
+            ("880086C3E88112", 7),
+            ("CE00C43D881120", 9),
+            ("D8005AC2A8F0", 1),
+            ("F600BC2D8F", 0),
+            ("9C005AC2F8F0", 0),
+            ("9C0141080250320F1802104A08", 1),
+        ]
+        .into_iter()
+        .for_each(|(hex, expected)| {
+            assert_eq!(part2(hex.to_string()), expected, "Value of {}", hex);
         });
     }
 
-    bits
+    fn lit(value: u64) -> Packet {
+        Packet::Literal { version: 0, value }
+    }
+
+    fn op(type_id: u8, children: Vec<Packet>) -> Packet {
+        Packet::Operator {
+            version: 0,
+            type_id,
+            children,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let mut reader = BitReader::from_hex("38006F45291200").unwrap();
+        assert_eq!(
+            Packet::parse(&mut reader),
+            Packet::Operator {
+                version: 1,
+                type_id: 6,
+                children: vec![
+                    Packet::Literal {
+                        version: 6,
+                        value: 10
+                    },
+                    Packet::Literal {
+                        version: 2,
+                        value: 20
+                    },
+                ],
+            }
+        );
+        assert_eq!(reader.position(), 49);
+    }
+
+    #[test]
+    fn test_operators() {
+        [
+            (op(0, vec![lit(1), lit(2), lit(3)]), 6),
+            (op(0, vec![lit(5)]), 5),
+            (op(1, vec![lit(2), lit(3), lit(4)]), 24),
+            (op(1, vec![lit(5)]), 5),
+            (op(2, vec![lit(7), lit(3), lit(9)]), 3),
+            (op(3, vec![lit(7), lit(3), lit(9)]), 9),
+            (op(5, vec![lit(5), lit(3)]), 1),
+            (op(5, vec![lit(3), lit(5)]), 0),
+            (op(6, vec![lit(3), lit(5)]), 1),
+            (op(6, vec![lit(5), lit(5)]), 0),
+            (op(7, vec![lit(4), lit(4)]), 1),
+            (op(7, vec![lit(4), lit(5)]), 0),
+            (
+                op(
+                    7,
+                    vec![op(0, vec![lit(1), lit(3)]), op(1, vec![lit(2), lit(2)])],
+                ),
+                1,
+            ),
+        ]
+        .into_iter()
+        .for_each(|(packet, expected)| {
+            assert_eq!(
+                packet.evaluate(),
+                expected,
+                "Failed to evaluate {:?}",
+                packet
+            );
+        });
+    }
+
+    #[test]
+    fn test_from_hex() {
+        let mut reader = BitReader::from_hex(" d2fe28\n").unwrap();
+        assert_eq!(
+            Packet::parse(&mut reader),
+            Packet::Literal {
+                version: 6,
+                value: 2021
+            }
+        );
+
+        assert_eq!(
+            BitReader::from_hex("D2FG28").unwrap_err(),
+            "Invalid hex digit 'G' at position 3"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let mut reader = BitReader::from_hex("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(
+            Packet::parse(&mut reader).to_string(),
+            "eq(v4)(sum(v2)(lit(v2)(1), lit(v4)(3)), product(v6)(lit(v0)(2), lit(v2)(2)))"
+        );
+        assert_eq!(
+            op(0, vec![lit(1), op(1, vec![lit(2), lit(3)])]).to_string(),
+            "sum(v0)(lit(v0)(1), product(v0)(lit(v0)(2), lit(v0)(3)))"
+        );
+    }
 }
@@ -1,17 +1,20 @@
-use crate::utils::day_setup::Utils;
+use crate::utils::day_setup::{AssertionError, Utils};
 use std::ops::RangeInclusive;
+use std::str::FromStr;
 
 /// Runs the Advent of Code puzzles for [Current Day](https://adventofcode.com/2021/day/17).
 ///
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part_single(part1, 1, 17, Some(6555));
-    Utils::run_part_single(part2, 2, 17, Some(4973));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part_single(part1, 1, 17, Some(6555))?;
+    Utils::run_part_single(part2, 2, 17, Some(4973))?;
+
+    Ok(())
 }
 
 fn part1(target_area: TargetArea) -> u32 {
@@ -33,46 +36,167 @@ impl TargetArea {
         (0..=(*self.y.start()).unsigned_abs() - 1).sum()
     }
 
+    /// Counts every initial velocity that lands the probe inside the target area.
+    ///
+    /// The search is bounded analytically: `x` must be fast enough for the drag to not stop
+    /// the probe before `x.start()` and slow enough to not skip past `x.end()` on the first
+    /// step, while `y` ranges from hitting `y.start()` directly up to the highest arc from
+    /// `max_height`.
     fn num_of_initial_velocities(&self) -> u16 {
-        let mut count = 0;
+        let min_x = (0..).find(|x| x * (x + 1) / 2 >= *self.x.start()).unwrap();
 
-        for y in *self.y.start()..=1 - *self.y.start() {
-            for x in 0..=*self.x.end() {
-                let mut x_pos = 0;
-                let mut y_pos = 0;
-
-                for i in 0..1000 {
-                    y_pos += y - i;
-
-                    if x - i > 0 {
-                        x_pos += x - i;
-                    }
-                    if *self.y.start() <= y_pos
-                        && y_pos <= *self.y.end()
-                        && *self.x.start() <= x_pos
-                        && x_pos <= *self.x.end()
-                    {
-                        count += 1;
-                        break;
-                    }
+        let mut count = 0;
+        for y in *self.y.start()..=-*self.y.start() - 1 {
+            for x in min_x..=*self.x.end() {
+                if self.is_hit(x, y) {
+                    count += 1;
                 }
             }
         }
 
         count
     }
+
+    /// Simulates a probe launched with velocity `(x, y)` and reports whether any step ends
+    /// inside the target area.
+    ///
+    /// The fastest upward throw reaches `y.start()` after `2 * |y.start()|` steps, so no probe
+    /// in the search range needs more than that.
+    fn is_hit(&self, mut x: i32, mut y: i32) -> bool {
+        let max_steps = 2 * self.y.start().unsigned_abs();
+        let (mut x_pos, mut y_pos) = (0, 0);
+
+        for _ in 0..max_steps {
+            x_pos += x;
+            y_pos += y;
+            x -= x.signum();
+            y -= 1;
+
+            if self.x.contains(&x_pos) && self.y.contains(&y_pos) {
+                return true;
+            }
+            if x_pos > *self.x.end() || y_pos < *self.y.start() {
+                return false;
+            }
+        }
+
+        false
+    }
+}
+
+impl TargetArea {
+    /// Parses one axis of the target area such as `x=20..30`, `y = -10..=-5` or `x 20 .. 30`.
+    ///
+    /// # Errors
+    ///   If the label is not `axis`, the range separator is missing or a bound is not a number.
+    fn parse_axis(axis: char, input: &str) -> Result<RangeInclusive<i32>, String> {
+        let range = input
+            .trim()
+            .strip_prefix(axis)
+            .ok_or_else(|| format!("Expected the {axis} axis in '{input}'"))?;
+        let range = range.trim_start();
+        let range = range.strip_prefix('=').unwrap_or(range);
+
+        let (start, end) = range
+            .split_once("..")
+            .ok_or_else(|| format!("Expected a '..' range in '{input}'"))?;
+        let end = end.trim_start();
+        let end = end.strip_prefix('=').unwrap_or(end);
+
+        let parse_bound = |bound: &str| {
+            bound
+                .trim()
+                .parse::<i32>()
+                .map_err(|e| format!("Invalid bound '{}' in '{input}': {e}", bound.trim()))
+        };
+        let (start, end) = (parse_bound(start)?, parse_bound(end)?);
+
+        Ok(start.min(end)..=start.max(end))
+    }
+}
+
+impl FromStr for TargetArea {
+    type Err = String;
+
+    /// Parses a line like `target area: x=20..30, y=-10..-5`.
+    ///
+    /// The `target area:` prefix is optional, and spaces around the labels, `=` signs and
+    /// range bounds are tolerated, as is the `..=` form of the ranges.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("target area:").unwrap_or(s);
+
+        let (x_raw, y_raw) = s
+            .split_once(',')
+            .ok_or_else(|| format!("Expected 'x' and 'y' ranges separated by ',' in '{s}'"))?;
+
+        Ok(TargetArea {
+            x: Self::parse_axis('x', x_raw)?,
+            y: Self::parse_axis('y', y_raw)?,
+        })
+    }
 }
 
 impl From<Vec<String>> for TargetArea {
     fn from(input: Vec<String>) -> Self {
-        let (x_raw, y_raw) = input.first().unwrap()[13..].trim().split_once(',').unwrap();
+        input
+            .first()
+            .expect("The input has no target area")
+            .parse()
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+#[cfg(test)]
+mod target_area_tests {
+    use super::*;
 
-        let (x1, x2) = x_raw[2..].split_once("..").unwrap();
-        let (y1, y2) = y_raw.trim()[2..].split_once("..").unwrap();
+    fn sample() -> TargetArea {
+        TargetArea::from(vec!["target area: x=20..30, y=-10..-5".to_string()])
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(part1(sample()), 45);
+        assert_eq!(part2(sample()), 112);
+    }
+
+    #[test]
+    fn test_is_hit() {
+        let target_area = sample();
+
+        assert!(target_area.is_hit(7, 2));
+        assert!(target_area.is_hit(6, 9));
+        assert!(target_area.is_hit(30, -10));
+        assert!(!target_area.is_hit(17, -4));
+        assert!(!target_area.is_hit(6, 10));
+    }
+
+    #[test]
+    fn test_parse_spacing_variants() {
+        for line in [
+            "target area: x=20..30, y=-10..-5",
+            "target area: x=20..=30, y=-10..=-5",
+            "target area:x = 20 .. 30,y = -10 .. -5",
+            "  x=20..30 , y=-10..-5  ",
+            "target area: x 20..30, y -10..-5",
+        ] {
+            let target_area = line.parse::<TargetArea>().unwrap();
+            assert_eq!(target_area.x, 20..=30, "{line}");
+            assert_eq!(target_area.y, -10..=-5, "{line}");
+        }
+    }
 
-        TargetArea {
-            x: x1.parse::<i32>().unwrap()..=x2.parse::<i32>().unwrap(),
-            y: y1.parse::<i32>().unwrap()..=y2.parse::<i32>().unwrap(),
+    #[test]
+    fn test_parse_malformed() {
+        for line in [
+            "target area: x=20..30",
+            "target area: y=-10..-5, x=20..30",
+            "target area: x=20-30, y=-10..-5",
+            "target area: x=20..thirty, y=-10..-5",
+            "",
+        ] {
+            assert!(line.parse::<TargetArea>().is_err(), "{line}");
         }
     }
 }
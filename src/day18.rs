@@ -1,4 +1,4 @@
-use crate::utils::day_setup::Utils;
+use crate::utils::day_setup::{AssertionError, Utils};
 use std::fmt;
 use std::num::ParseIntError;
 use std::ops::AddAssign;
@@ -10,12 +10,14 @@ use std::str::FromStr;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part(part1, 1, 18, Some(3051));
-    Utils::run_part(part2, 2, 18, Some(4812));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 18, Some(3051))?;
+    Utils::run_part(part2, 2, 18, Some(4812))?;
+
+    Ok(())
 }
 
 fn part1(mut input: Vec<SnailFish>) -> u64 {
@@ -29,6 +31,9 @@ fn part1(mut input: Vec<SnailFish>) -> u64 {
         .magnitude()
 }
 
+/// Finds the largest magnitude of the sum of any two different snail fish numbers, spawning a
+/// thread for every left-hand side.
+#[cfg(not(feature = "rayon"))]
 fn part2(input: Vec<SnailFish>) -> u64 {
     let (tx, rx) = std::sync::mpsc::channel();
 
@@ -42,12 +47,7 @@ fn part2(input: Vec<SnailFish>) -> u64 {
 
                 for j in 0..input.len() {
                     if i != j {
-                        let mut lhs = input[i].clone();
-                        lhs += input[j].clone();
-                        let mut rhs = input[j].clone();
-                        rhs += input[i].clone();
-
-                        max_magnitude = max_magnitude.max(lhs.magnitude().max(rhs.magnitude()));
+                        max_magnitude = max_magnitude.max(sum_magnitude(&input[i], &input[j]));
                     }
                 }
 
@@ -60,6 +60,29 @@ fn part2(input: Vec<SnailFish>) -> u64 {
     rx.into_iter().max().unwrap()
 }
 
+/// Finds the largest magnitude of the sum of any two different snail fish numbers, letting rayon
+/// spread the ordered pairs over its thread pool.
+#[cfg(feature = "rayon")]
+fn part2(input: Vec<SnailFish>) -> u64 {
+    use rayon::prelude::*;
+
+    let len = input.len();
+    (0..len)
+        .into_par_iter()
+        .flat_map_iter(|i| (0..len).filter(move |&j| i != j).map(move |j| (i, j)))
+        .map(|(i, j)| sum_magnitude(&input[i], &input[j]))
+        .max()
+        .unwrap()
+}
+
+/// Returns the magnitude of `lhs + rhs`. Snail fish addition is not commutative, so both orders
+/// of a pair need to be checked separately.
+fn sum_magnitude(lhs: &SnailFish, rhs: &SnailFish) -> u64 {
+    let mut sum = lhs.clone();
+    sum += rhs.clone();
+    sum.magnitude()
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum SnailToken {
     OpenParen,
@@ -67,6 +90,13 @@ enum SnailToken {
     Number(u8),
 }
 
+/// The number of reduction steps it took to reduce a sum of snail fish numbers.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+struct ReductionStats {
+    explodes: usize,
+    splits: usize,
+}
+
 #[derive(Eq)]
 struct SnailFish {
     tokens: Vec<SnailToken>,
@@ -104,71 +134,64 @@ impl SnailFish {
         unreachable!("Should never reach here as lists should always be in pairs")
     }
 
-    fn explode(&mut self) {
-        fn replace_pair(tokens: &mut Vec<SnailToken>, index: usize) -> (SnailToken, SnailToken) {
-            assert_eq!(tokens[index], SnailToken::OpenParen);
-            *tokens.get_mut(index).unwrap() = SnailToken::Number(0); // replace [ with 0
-            let num1 = tokens.remove(index + 1); // Num1
-            assert!(matches!(num1, SnailToken::Number(_)));
-            let num2 = tokens.remove(index + 1); // Num2
-            assert!(matches!(num2, SnailToken::Number(_)));
-            assert_eq!(tokens.remove(index + 1), SnailToken::CloseParen); // ]
-
-            (num1, num2)
+    /// Explodes every pair nested inside four pairs.
+    ///
+    /// Exploding a pair only removes nesting and adds to neighbouring regular numbers, so it can
+    /// never create a new pair to explode. A single left-to-right pass is therefore enough.
+    ///
+    /// # Returns
+    /// The number of pairs that exploded.
+    fn explode(&mut self) -> usize {
+        fn replace_pair(tokens: &mut Vec<SnailToken>, index: usize) -> (u8, u8) {
+            let pair = match tokens[index..index + 4] {
+                [SnailToken::OpenParen, SnailToken::Number(num1), SnailToken::Number(num2), SnailToken::CloseParen] => {
+                    (num1, num2)
+                }
+                _ => unreachable!(
+                    "Only pairs of two regular numbers can explode: {:?}",
+                    &tokens[index..index + 4]
+                ),
+            };
+            tokens[index] = SnailToken::Number(0); // replace [num1,num2] with 0
+            tokens.drain(index + 1..index + 4);
+
+            pair
         }
 
-        loop {
-            let mut exploded = false;
-            let mut depth = 0_u8;
-            let mut index = 0;
-
-            while index < self.tokens.len() {
-                match self.tokens[index] {
-                    SnailToken::OpenParen => {
-                        depth += 1;
-
-                        if depth >= 5 {
-                            let (num1, num2) = replace_pair(&mut self.tokens, index);
-
-                            if let Some(SnailToken::Number(n)) = self.tokens[..index]
-                                .iter_mut()
-                                .rfind(|tok| matches!(tok, SnailToken::Number(_)))
-                            {
-                                *n += match num1 {
-                                    SnailToken::Number(num1) => num1,
-                                    _ => {
-                                        unreachable!("Should never be anything other than a number")
-                                    }
-                                }
-                            }
+        let mut explodes = 0;
+        let mut depth = 0_u8;
+        let mut last_number = None;
+        let mut index = 0;
 
-                            if let Some(SnailToken::Number(n)) = self.tokens[index + 1..]
-                                .iter_mut()
-                                .find(|tok| matches!(tok, SnailToken::Number(_)))
-                            {
-                                *n += match num2 {
-                                    SnailToken::Number(num2) => num2,
-                                    _ => {
-                                        unreachable!("Should never be anything other than a number")
-                                    }
-                                }
-                            }
+        while index < self.tokens.len() {
+            match self.tokens[index] {
+                // This pair would be the fifth level of nesting
+                SnailToken::OpenParen if depth >= 4 => {
+                    let (num1, num2) = replace_pair(&mut self.tokens, index);
 
-                            depth -= 1; // We have remove the current pair so we are no longer at that depth
-                            exploded = true;
-                        }
+                    if let Some(SnailToken::Number(n)) = last_number.map(|i| &mut self.tokens[i]) {
+                        *n += num1;
                     }
-                    SnailToken::CloseParen => depth -= 1,
-                    SnailToken::Number(_) => (),
-                }
 
-                index += 1;
-            }
+                    if let Some(SnailToken::Number(n)) = self.tokens[index + 1..]
+                        .iter_mut()
+                        .find(|tok| matches!(tok, SnailToken::Number(_)))
+                    {
+                        *n += num2;
+                    }
 
-            if !exploded {
-                break;
+                    last_number = Some(index);
+                    explodes += 1;
+                }
+                SnailToken::OpenParen => depth += 1,
+                SnailToken::CloseParen => depth -= 1,
+                SnailToken::Number(_) => last_number = Some(index),
             }
+
+            index += 1;
         }
+
+        explodes
     }
 
     fn split(&mut self) -> bool {
@@ -198,6 +221,24 @@ impl SnailFish {
         false
     }
 
+    /// Adds `rhs` to this number and reduces the result, like `+=`.
+    ///
+    /// # Returns
+    /// How many explode and split steps the reduction took.
+    fn add_assign_counted(&mut self, rhs: Self) -> ReductionStats {
+        let mut stats = ReductionStats::default();
+        self.merge(rhs);
+        loop {
+            stats.explodes += self.explode();
+            if !self.split() {
+                break;
+            }
+            stats.splits += 1;
+        }
+
+        stats
+    }
+
     fn merge(&mut self, other: Self) {
         self.tokens.insert(0, SnailToken::OpenParen);
         self.tokens.extend(other.tokens);
@@ -221,64 +262,38 @@ impl PartialEq for SnailFish {
 
 impl AddAssign for SnailFish {
     fn add_assign(&mut self, rhs: Self) {
-        self.merge(rhs);
-        loop {
-            self.explode();
-            if !self.split() {
-                break;
-            }
-        }
+        self.add_assign_counted(rhs);
     }
 }
 
-impl fmt::Debug for SnailFish {
+impl fmt::Display for SnailFish {
+    /// Writes the snail fish number in its canonical `[a,b]` notation.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut iter = self.tokens.iter().peekable();
-        let mut i = 0;
-        while let Some(tok) = iter.next() {
+        let mut prev = None;
+        for &tok in self.tokens.iter() {
+            // Elements of a pair are separated by a comma
+            if matches!(prev, Some(SnailToken::Number(_) | SnailToken::CloseParen))
+                && matches!(tok, SnailToken::Number(_) | SnailToken::OpenParen)
+            {
+                write!(f, ",")?;
+            }
             match tok {
-                SnailToken::OpenParen => {
-                    i += 1;
-                    write!(f, "[")?
-                }
-                SnailToken::CloseParen => {
-                    i -= 1;
-                    match iter.peek() {
-                        Some(SnailToken::Number(n)) => {
-                            write!(f, "],{}", n)?;
-                            iter.next();
-                        }
-                        Some(SnailToken::OpenParen) => {
-                            write!(f, "],[")?;
-                            i += 1;
-                            iter.next();
-                        }
-                        _ => write!(f, "]")?,
-                    }
-                }
-                SnailToken::Number(n) => match iter.peek() {
-                    Some(SnailToken::Number(n2)) => {
-                        write!(f, "{},{}", n, n2)?;
-                        iter.next();
-                    }
-                    Some(SnailToken::OpenParen) => {
-                        write!(f, "{},[", n)?;
-                        i += 1;
-                        iter.next();
-                    }
-                    None => {
-                        debug_assert!(false, "Cannot end list with a number: {:?}", self.tokens);
-                        unreachable!("Numbers should never end the list")
-                    }
-                    _ => write!(f, "{}", n)?,
-                },
+                SnailToken::OpenParen => write!(f, "[")?,
+                SnailToken::CloseParen => write!(f, "]")?,
+                SnailToken::Number(n) => write!(f, "{}", n)?,
             }
+            prev = Some(tok);
         }
-        assert_eq!(i, 0);
         Ok(())
     }
 }
 
+impl fmt::Debug for SnailFish {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
 impl FromStr for SnailFish {
     type Err = ParseIntError;
 
@@ -519,4 +534,99 @@ mod snail_fish_tests {
             assert_eq!(a, expected, "Failed to add SnailFish for test {}", idx);
         });
     }
+
+    #[test]
+    fn test_display_round_trip() {
+        [
+            "[9,[8,7]]",
+            "[9,[8,7],[6,5]]",
+            "[[[[0,7],4],[15,[0,13]]],[1,1]]",
+            "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+            "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+            "[[[[[9,8],1],2],3],4]",
+            "[[[[0,9],2],3],4]",
+            "[7,[6,[5,[4,[3,2]]]]]",
+            "[7,[6,[5,[7,0]]]]",
+            "[[6,[5,[4,[3,2]]]],1]",
+            "[[6,[5,[7,0]]],3]",
+            "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+            "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]",
+            "[[1,2],[[3,4],5]]",
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+            "[[[[1,1],[2,2]],[3,3]],[4,4]]",
+            "[[[[3,0],[5,3]],[4,4]],[5,5]]",
+            "[[[[5,0],[7,4]],[5,5]],[6,6]]",
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+            "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]",
+            "[[[[4,3],4],4],[7,[[8,4],9]]]",
+            "[1,1]",
+            "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+            "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+            "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]",
+            "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+            "[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]",
+            "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+            "[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]",
+            "[7,[5,[[3,8],[1,4]]]]",
+            "[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]",
+            "[[2,[2,2]],[8,[8,1]]]",
+            "[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]",
+            "[2,9]",
+            "[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]",
+            "[1,[[[9,3],9],[[9,0],[0,7]]]]",
+            "[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]",
+            "[[[5,[7,4]],7],1]",
+            "[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]",
+            "[[[[4,2],2],6],[8,7]]",
+        ]
+        .into_iter()
+        .for_each(|input| {
+            let snail_fish = input.parse::<SnailFish>().unwrap();
+            assert_eq!(snail_fish.to_string(), input);
+            assert_eq!(
+                snail_fish.to_string().parse::<SnailFish>().unwrap(),
+                snail_fish
+            );
+        });
+    }
+
+    #[test]
+    fn test_add_assign_counted() {
+        let mut snail_fish = "[[[[4,3],4],4],[7,[[8,4],9]]]"
+            .parse::<SnailFish>()
+            .unwrap();
+        let stats = snail_fish.add_assign_counted("[1,1]".parse().unwrap());
+
+        assert_eq!(snail_fish.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+        assert_eq!(
+            stats,
+            ReductionStats {
+                explodes: 3,
+                splits: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_sample_homework() {
+        let input = [
+            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+            "[[[[5,4],[7,7]],8],[[8,3],8]]",
+            "[[9,3],[[9,9],[6,[4,9]]]]",
+            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+        ]
+        .map(|line| line.parse::<SnailFish>().unwrap())
+        .to_vec();
+
+        assert_eq!(part1(input.clone()), 4140);
+        assert_eq!(part2(input), 3993);
+    }
 }
@@ -1,5 +1,6 @@
-use crate::utils::day_setup::Utils;
-use std::collections::HashSet;
+use crate::utils::coordinate_system::Coordinate3D;
+use crate::utils::day_setup::{AssertionError, Utils};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::{Debug, Formatter};
 
 /// Runs the Advent of Code puzzles for [Current Day](https://adventofcode.com/2021/day/19).
@@ -7,22 +8,30 @@ use std::fmt::{Debug, Formatter};
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part_single(part1, 1, 0, None);
-    Utils::run_part(part2, 2, 0, None);
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part_single(part1, 1, 19, Some(326))?;
+    Utils::run_part_single(part2, 2, 19, Some(10630))?;
+
+    Ok(())
 }
 
-fn part1(input: ScannerList) -> u64 {
-    println!("Part 1: {:#?}", input);
-    0
+fn part1(mut scanner_list: ScannerList) -> usize {
+    scanner_list
+        .reconstruct()
+        .expect("The scanners cannot be stitched together");
+
+    scanner_list.beacon_count()
 }
 
-fn part2(input: Vec<String>) -> u64 {
-    println!("Part 2 {:#?}", input);
-    0
+fn part2(mut scanner_list: ScannerList) -> i32 {
+    scanner_list
+        .reconstruct()
+        .expect("The scanners cannot be stitched together");
+
+    scanner_list.max_scanner_distance()
 }
 
 struct ScannerList {
@@ -31,15 +40,180 @@ struct ScannerList {
 
 struct Scanner {
     name: u16,
-    beacons: HashSet<Beacon>,
+    beacons: HashSet<Coordinate3D>,
+    /// The position of the scanner relative to scanner 0, once it has been resolved.
+    position: Option<Coordinate3D>,
+    /// How many pairs of beacons lie at each squared distance. Distances do not change under
+    /// rotation or translation, so they stay valid once the beacons are moved into another frame.
+    fingerprint: HashMap<i32, usize>,
 }
 
-#[derive(Hash, Copy, Clone, Eq, PartialEq)]
-struct Beacon(i32, i32, i32);
+impl ScannerList {
+    /// The number of beacons two scanners need to have in common to be considered overlapping.
+    const MIN_OVERLAP: usize = 12;
+    /// The number of beacon pairs among [`ScannerList::MIN_OVERLAP`] beacons, i.e. the minimum
+    /// number of distances two overlapping scanners have in common.
+    const MIN_SHARED_DISTANCES: usize = Self::MIN_OVERLAP * (Self::MIN_OVERLAP - 1) / 2;
 
-impl Debug for Beacon {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?},{:?},{:?}", self.0, self.1, self.2)
+    /// Moves every scanner into the coordinate frame of scanner 0.
+    ///
+    /// Starting from scanner 0, every unresolved scanner is aligned against the scanners resolved
+    /// so far. Once aligned, its beacons are rewritten relative to scanner 0 and its `position`
+    /// is set, so it can in turn be used as a reference for the remaining scanners.
+    ///
+    /// # Errors
+    ///   If some scanners do not overlap with any of the others.
+    fn reconstruct(&mut self) -> Result<(), String> {
+        let Some(first) = self.scanners.first_mut() else {
+            return Ok(());
+        };
+        first.position = Some(Coordinate3D::default());
+
+        let mut references = VecDeque::from([0]);
+        while let Some(reference) = references.pop_front() {
+            for i in 0..self.scanners.len() {
+                if self.scanners[i].position.is_some()
+                    || self.scanners[i].shared_distances(&self.scanners[reference])
+                        < Self::MIN_SHARED_DISTANCES
+                {
+                    continue;
+                }
+
+                if let Some((beacons, position)) =
+                    self.scanners[i].align_with(&self.scanners[reference])
+                {
+                    self.scanners[i].beacons = beacons;
+                    self.scanners[i].position = Some(position);
+                    references.push_back(i);
+                }
+            }
+        }
+
+        let unresolved = self
+            .scanners
+            .iter()
+            .filter(|scanner| scanner.position.is_none())
+            .map(|scanner| scanner.name.to_string())
+            .collect::<Vec<_>>();
+        if unresolved.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "No overlap found for scanners {}",
+                unresolved.join(", ")
+            ))
+        }
+    }
+
+    /// Returns the number of distinct beacons seen by all scanners.
+    ///
+    /// Only meaningful after [`ScannerList::reconstruct`], when all beacons share one frame.
+    fn beacon_count(&self) -> usize {
+        self.scanners
+            .iter()
+            .flat_map(|scanner| scanner.beacons.iter())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Returns the largest Manhattan distance between any two resolved scanners.
+    ///
+    /// Scanners that have not been resolved by [`ScannerList::reconstruct`] are ignored.
+    fn max_scanner_distance(&self) -> i32 {
+        let positions = self
+            .scanners
+            .iter()
+            .filter_map(|scanner| scanner.position)
+            .collect::<Vec<_>>();
+
+        positions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| {
+                positions[i + 1..]
+                    .iter()
+                    .map(|b| a.manhattan_distance_to(b))
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Scanner {
+    fn new(name: u16, beacons: HashSet<Coordinate3D>) -> Self {
+        let fingerprint = Self::fingerprint(&beacons);
+        Scanner {
+            name,
+            beacons,
+            position: None,
+            fingerprint,
+        }
+    }
+
+    /// Counts the pairs of `beacons` at each squared distance.
+    fn fingerprint(beacons: &HashSet<Coordinate3D>) -> HashMap<i32, usize> {
+        let beacons = beacons.iter().collect::<Vec<_>>();
+
+        let mut fingerprint = HashMap::new();
+        for (i, &&a) in beacons.iter().enumerate() {
+            for &&b in &beacons[i + 1..] {
+                let Coordinate3D { x, y, z } = a - b;
+                *fingerprint.entry(x * x + y * y + z * z).or_insert(0) += 1;
+            }
+        }
+
+        fingerprint
+    }
+
+    /// Returns the number of beacon pairs this scanner can match by distance with `other`.
+    ///
+    /// A distance shared by several pairs is counted as many times as both scanners have it, so
+    /// overlapping beacons that happen to repeat a distance still add up to every shared pair.
+    fn shared_distances(&self, other: &Scanner) -> usize {
+        self.fingerprint
+            .iter()
+            .filter_map(|(distance, &count)| {
+                other
+                    .fingerprint
+                    .get(distance)
+                    .map(|&other_count| count.min(other_count))
+            })
+            .sum()
+    }
+
+    /// Tries every rotation of this scanner's beacons against the beacons of `reference`, looking
+    /// for a translation under which at least [`ScannerList::MIN_OVERLAP`] of them coincide.
+    ///
+    /// Returns the beacons moved into the frame of `reference` along with the position of this
+    /// scanner in that frame.
+    fn align_with(&self, reference: &Scanner) -> Option<(HashSet<Coordinate3D>, Coordinate3D)> {
+        let rotated = self
+            .beacons
+            .iter()
+            .map(|beacon| beacon.rotations())
+            .collect::<Vec<_>>();
+
+        for rotation in 0..24 {
+            let mut offsets = HashMap::new();
+            for beacon in rotated.iter().map(|rotations| rotations[rotation]) {
+                for known in &reference.beacons {
+                    *offsets.entry(*known - beacon).or_insert(0) += 1;
+                }
+            }
+
+            if let Some((&offset, _)) = offsets
+                .iter()
+                .find(|(_, &count)| count >= ScannerList::MIN_OVERLAP)
+            {
+                let beacons = rotated
+                    .iter()
+                    .map(|rotations| rotations[rotation] + offset)
+                    .collect();
+                return Some((beacons, offset));
+            }
+        }
+
+        None
     }
 }
 
@@ -74,30 +248,126 @@ impl From<Vec<String>> for ScannerList {
             if line.starts_with("---") {
                 // Skip
             } else if line.is_empty() {
-                scanners.push(Scanner {
-                    name: count,
-                    beacons,
-                });
+                scanners.push(Scanner::new(count, beacons));
                 count += 1;
                 beacons = HashSet::new();
             } else {
                 // The actual beacon information
-                let mut beacon_info = line.split(',');
-                beacons.insert(Beacon(
-                    beacon_info.next().unwrap().parse::<i32>().unwrap(),
-                    beacon_info.next().unwrap().parse::<i32>().unwrap(),
-                    beacon_info.next().unwrap().parse::<i32>().unwrap(),
-                ));
+                beacons.insert(line.parse::<Coordinate3D>().unwrap());
             }
         }
 
         if !beacons.is_empty() {
-            scanners.push(Scanner {
-                name: count,
-                beacons,
-            });
+            scanners.push(Scanner::new(count, beacons));
         }
 
         ScannerList { scanners }
     }
 }
+
+#[cfg(test)]
+mod scanner_list_tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("inputs/fixtures/day19_sample.txt");
+
+    fn sample() -> ScannerList {
+        ScannerList::from(SAMPLE.lines().map(String::from).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_reconstruct() {
+        let mut scanner_list = sample();
+        scanner_list.reconstruct().unwrap();
+
+        assert_eq!(scanner_list.beacon_count(), 79);
+        assert_eq!(
+            scanner_list.scanners[1].position,
+            Some(Coordinate3D::new(68, -1246, -43))
+        );
+        assert_eq!(
+            scanner_list.scanners[4].position,
+            Some(Coordinate3D::new(-20, -1133, 1061))
+        );
+    }
+
+    #[test]
+    fn test_max_scanner_distance() {
+        let mut scanner_list = sample();
+        assert_eq!(scanner_list.max_scanner_distance(), 0);
+
+        scanner_list.reconstruct().unwrap();
+        assert_eq!(scanner_list.max_scanner_distance(), 3621);
+        assert_eq!(part2(sample()), 3621);
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let scanner = Scanner::new(
+            0,
+            HashSet::from([
+                Coordinate3D::new(0, 0, 0),
+                Coordinate3D::new(1, 0, 0),
+                Coordinate3D::new(0, 2, 0),
+                Coordinate3D::new(0, 0, 3),
+            ]),
+        );
+        // 1, 4, 9 from the origin, then 1 + 4, 1 + 9 and 4 + 9 between the others
+        assert_eq!(
+            scanner.fingerprint,
+            HashMap::from([1, 4, 9, 5, 10, 13].map(|distance| (distance, 1)))
+        );
+
+        let scanner_list = sample();
+        let [first, second, ..] = &scanner_list.scanners[..] else {
+            unreachable!()
+        };
+        assert_eq!(first.fingerprint.values().sum::<usize>(), 300);
+        assert_eq!(first.shared_distances(second), 66);
+        assert_eq!(
+            first.shared_distances(second),
+            second.shared_distances(first)
+        );
+    }
+
+    #[test]
+    fn test_repeated_distances() {
+        // The first three beacons sit 1 apart twice, so two of the 66 pairs share a distance
+        let beacons = [(0, 0, 0), (1, 0, 0), (2, 0, 0)]
+            .into_iter()
+            .chain((3..12).map(|i| (i * 7, i * i * 3, i * i * i)))
+            .map(|(x, y, z)| Coordinate3D::new(x, y, z))
+            .collect::<HashSet<_>>();
+        let offset = Coordinate3D::new(100, -20, 7);
+        let moved = beacons
+            .iter()
+            .map(|beacon| beacon.rotations()[5] + offset)
+            .collect::<HashSet<_>>();
+
+        let mut scanner_list = ScannerList {
+            scanners: vec![Scanner::new(0, beacons), Scanner::new(1, moved)],
+        };
+        let [first, second] = &scanner_list.scanners[..] else {
+            unreachable!()
+        };
+        assert!(first.fingerprint.len() < ScannerList::MIN_SHARED_DISTANCES);
+        assert_eq!(
+            first.shared_distances(second),
+            ScannerList::MIN_SHARED_DISTANCES
+        );
+
+        scanner_list.reconstruct().unwrap();
+        assert_eq!(scanner_list.beacon_count(), 12);
+    }
+
+    #[test]
+    fn test_reconstruct_disjoint() {
+        let mut scanner_list = sample();
+        scanner_list.scanners[3] = Scanner::new(3, HashSet::from([Coordinate3D::new(1, 2, 3)]));
+
+        assert_eq!(
+            scanner_list.reconstruct(),
+            Err("No overlap found for scanners 3".to_string())
+        );
+    }
+}
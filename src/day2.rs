@@ -1,45 +1,105 @@
 use crate::utils::day_setup;
+use std::str::FromStr;
 
-pub fn run() {
-    // run_part(function, part_num, day_num)
-    day_setup::Utils::run_part(part1, 1, 2, Some(1714680));
-    day_setup::Utils::run_part(part2, 2, 2, Some(1963088820));
+pub fn run() -> Result<(), day_setup::AssertionError> {
+    // run_part(function, part_num, day_num, expected)
+    day_setup::Utils::run_part(part1, 1, 2, Some(1714680))?;
+    day_setup::Utils::run_part(part2, 2, 2, Some(1963088820))?;
+
+    Ok(())
 }
 
-fn part1(input: Vec<String>) -> u64 {
-    let mut horizontal: u32 = 0;
-    let mut depth: i16 = 0;
-    for line in input {
-        let mut info = line.split_whitespace();
-        let name = info.next().unwrap();
-        let x = info.next().unwrap().parse::<u32>().unwrap();
-        match name {
-            "forward" => horizontal += x,
-            "down" => depth += x as i16,
-            "up" => depth -= x as i16,
-            _ => unreachable!(),
+fn part1(input: Vec<Command>) -> u64 {
+    let mut horizontal: i64 = 0;
+    let mut depth: i64 = 0;
+    for command in input {
+        match command {
+            Command::Forward(x) => horizontal += x as i64,
+            Command::Down(x) => depth += x as i64,
+            Command::Up(x) => depth -= x as i64,
         }
     }
-    (horizontal as i32 * depth as i32) as u64
+    (horizontal * depth) as u64
 }
 
-fn part2(input: Vec<String>) -> u64 {
-    let mut horizontal: u32 = 0;
-    let mut depth: u32 = 0;
-    let mut aim: i16 = 0;
-    for line in input {
-        let mut info = line.split_whitespace();
-        let name = info.next().unwrap();
-        let x = info.next().unwrap().parse::<u32>().unwrap();
-        match name {
-            "forward" => {
-                horizontal += x;
-                depth += aim as u32 * x;
+fn part2(input: Vec<Command>) -> u64 {
+    let mut horizontal: i64 = 0;
+    let mut depth: i64 = 0;
+    let mut aim: i64 = 0;
+    for command in input {
+        match command {
+            Command::Forward(x) => {
+                horizontal += x as i64;
+                depth += aim * x as i64;
             }
-            "down" => aim += x as i16,
-            "up" => aim -= x as i16,
-            _ => unreachable!(),
+            Command::Down(x) => aim += x as i64,
+            Command::Up(x) => aim -= x as i64,
         }
     }
     (horizontal * depth) as u64
 }
+
+/// A single submarine command, such as `forward 5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    /// Parses a command made of a direction and a distance separated by a space.
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (name, x) = line
+            .split_once(' ')
+            .ok_or_else(|| format!("Command '{line}' is not of the form 'forward 5'"))?;
+        let x = x
+            .parse::<u32>()
+            .map_err(|err| format!("Invalid distance in command '{line}': {err}"))?;
+
+        match name {
+            "forward" => Ok(Command::Forward(x)),
+            "down" => Ok(Command::Down(x)),
+            "up" => Ok(Command::Up(x)),
+            _ => Err(format!("Unknown direction '{name}' in command '{line}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod command_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!("forward 5".parse(), Ok(Command::Forward(5)));
+        assert_eq!("down 8".parse(), Ok(Command::Down(8)));
+        assert_eq!("up 3".parse(), Ok(Command::Up(3)));
+
+        assert_eq!(
+            "sideways 3".parse::<Command>(),
+            Err("Unknown direction 'sideways' in command 'sideways 3'".to_string())
+        );
+        assert!("forward".parse::<Command>().is_err());
+        assert!("forward x".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn test_sample() {
+        let sample = [
+            "forward 5",
+            "down 5",
+            "forward 8",
+            "up 3",
+            "down 8",
+            "forward 2",
+        ]
+        .map(|line| line.parse().unwrap())
+        .to_vec();
+
+        assert_eq!(part1(sample.clone()), 150);
+        assert_eq!(part2(sample), 900);
+    }
+}
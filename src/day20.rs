@@ -1,6 +1,6 @@
 use crate::utils::coordinate_system::direction::FullDirection;
 use crate::utils::coordinate_system::Coordinate;
-use crate::utils::day_setup::Utils;
+use crate::utils::day_setup::{AssertionError, Utils};
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::mem;
@@ -11,26 +11,23 @@ use std::ops::RangeInclusive;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    // 5479 too Low
-    // 5525 X
-    // 5539 X
-    // 5971 too high
-    Utils::run_part_single(part1, 1, 0, None);
-    Utils::run_part_single(part2, 2, 0, None);
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part_single(part1, 1, 20, Some(5573))?;
+    Utils::run_part_single(part2, 2, 20, Some(20097))?;
+
+    Ok(())
 }
 
 fn part1(mut image_enhancer: ImageEnhancer) -> usize {
-    println!("{:#?}", image_enhancer.image);
-    image_enhancer.enhance::<2>();
+    image_enhancer.enhance_times(2);
     image_enhancer.image.pixel_count()
 }
 
 fn part2(mut image_enhancer: ImageEnhancer) -> usize {
-    image_enhancer.enhance::<0>();
+    image_enhancer.enhance_times(50);
     image_enhancer.image.pixel_count()
 }
 
@@ -41,11 +38,10 @@ struct ImageEnhancer {
 }
 
 impl ImageEnhancer {
-    /// Enhances the image `N` times.
-    fn enhance<const N: usize>(&mut self) {
-        for _ in 0..N {
+    /// Enhances the image `times` times.
+    fn enhance_times(&mut self, times: usize) {
+        for _ in 0..times {
             self.enhance_once();
-            println!("{:#?}", self.image);
         }
     }
 
@@ -82,7 +78,7 @@ impl ImageEnhancer {
     fn enhance_once(&mut self) {
         let (row_range, column_range) = self.image.loop_range();
 
-        for i in row_range {
+        for i in row_range.clone() {
             for j in column_range.clone() {
                 let curr_coord = Coordinate::new(i, j);
                 let pixel = self.decode_number(&curr_coord);
@@ -92,7 +88,12 @@ impl ImageEnhancer {
             }
         }
 
-        self.image.flush();
+        // Every pixel at infinity sees 9 background pixels, so they all become the same pixel
+        let background = match self.image.background {
+            Some(_) => self.enhancement_algorithm[0b111_111_111],
+            None => self.enhancement_algorithm[0],
+        };
+        self.image.flush(row_range, column_range, background);
     }
 }
 
@@ -102,8 +103,8 @@ struct Image {
     front_buffer: HashSet<Coordinate>,
     back_buffer: HashSet<Coordinate>,
 
-    // (AllOff, AllOn)
-    infinity: (Pixel, Pixel),
+    /// The pixel shared by every coordinate outside the width and height ranges.
+    background: Pixel,
 }
 
 type RowRange = RangeInclusive<i32>;
@@ -125,32 +126,20 @@ impl Image {
         self.back_buffer.insert(*coord);
     }
 
-    fn flush(&mut self) {
+    /// Makes the pixels written since the last flush the current image.
+    ///
+    /// # Arguments
+    /// * `height_range` - The rows that were written.
+    /// * `width_range` - The columns that were written.
+    /// * `background` - The pixel of every coordinate outside of those ranges.
+    fn flush(&mut self, height_range: RowRange, width_range: ColumnRange, background: Pixel) {
         // Swap the pixels storage
         mem::swap(&mut self.front_buffer, &mut self.back_buffer);
         self.back_buffer.clear();
 
-        if self.infinity.0.is_some() {
-            // Swap the infinity pixels
-            mem::swap(&mut self.infinity.0, &mut self.infinity.1);
-        }
-
-        // Redefine the range
-        let mut min_width = i32::MAX;
-        let mut max_width = 0;
-        let mut min_height = i32::MAX;
-        let mut max_height = 0;
-
-        for pixels in &self.front_buffer {
-            min_width = min_width.min(pixels.j);
-            max_width = max_width.max(pixels.j);
-
-            min_height = min_height.min(pixels.i);
-            max_height = max_height.max(pixels.i);
-        }
-
-        self.width_range = min_width..=max_width;
-        self.height_range = min_height..=max_height;
+        self.height_range = height_range;
+        self.width_range = width_range;
+        self.background = background;
     }
 
     fn at_infinity(&self, coordinate: &Coordinate) -> bool {
@@ -162,24 +151,75 @@ impl Image {
     /// Gets the pixel at the given coordinate.
     fn get_pixel(&self, coord: &Coordinate) -> Pixel {
         if self.at_infinity(coord) {
-            if self.infinity.0.is_some() {
-                Some(())
-            } else {
-                None
-            }
+            self.background
         } else {
             self.front_buffer.get(coord).map(|_| ())
         }
     }
 }
 
+impl Image {
+    /// Draws the image over its height and width ranges, with `#` for lit and `.` for dark pixels.
+    ///
+    /// # Returns
+    /// A `String` with one line per row, separated by `'\n'` (no trailing newline).
+    #[allow(dead_code)]
+    fn to_ascii(&self) -> String {
+        self.height_range
+            .clone()
+            .map(|i| {
+                self.width_range
+                    .clone()
+                    .map(|j| match self.get_pixel(&Coordinate::new(i, j)) {
+                        Some(_) => '#',
+                        None => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Saves the image as a black and white PNG, with one pixel per cell and lit cells in white.
+    ///
+    /// An image without any rows or columns is written as a single dark pixel.
+    ///
+    /// # Arguments
+    /// * `path` - Where to write the PNG file.
+    ///
+    /// # Errors
+    /// If the file cannot be created or the PNG cannot be encoded.
+    #[cfg(feature = "image-export")]
+    #[allow(dead_code)]
+    fn write_png(&self, path: &std::path::Path) -> std::io::Result<()> {
+        let height = self.height_range.clone().count().max(1);
+        let width = self.width_range.clone().count().max(1);
+        let mut data = vec![0_u8; width * height];
+        for (row, i) in self.height_range.clone().enumerate() {
+            for (col, j) in self.width_range.clone().enumerate() {
+                if self.get_pixel(&Coordinate::new(i, j)).is_some() {
+                    data[row * width + col] = u8::MAX;
+                }
+            }
+        }
+
+        let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&data))
+            .map_err(std::io::Error::other)
+    }
+}
+
 impl Debug for ImageEnhancer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Enhancement Algorithm: [ ")?;
         for (i, e) in self.enhancement_algorithm.iter().enumerate() {
-            match e {
-                Some(_) => write!(f, "{i}, ")?,
-                None => (),
+            if e.is_some() {
+                write!(f, "{i}, ")?
             }
         }
         writeln!(f, "]")?;
@@ -189,26 +229,10 @@ impl Debug for ImageEnhancer {
 
 impl Debug for Image {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(
-            f,
-            "Pixels: ({}, {})",
-            self.infinity.0.is_some(),
-            self.infinity.1.is_some()
-        )?;
+        writeln!(f, "Background Lit: {}", self.background.is_some())?;
         writeln!(f, "Width Range: {:#?}", self.width_range)?;
         writeln!(f, "Height Range: {:#?}", self.height_range)?;
-        writeln!(f, "Pixel Count: {}", self.pixel_count())?;
-        // for x in self.height_range.clone() {
-        //     for y in self.width_range.clone() {
-        //         if self.get_pixel(&Coordinate::new(x, y)).is_some() {
-        //             write!(f, "# ")?;
-        //         } else {
-        //             write!(f, ". ")?;
-        //         }
-        //     }
-        //     writeln!(f)?;
-        // }
-        Ok(())
+        writeln!(f, "Pixel Count: {}", self.pixel_count())
     }
 }
 
@@ -235,29 +259,112 @@ impl From<Vec<String>> for ImageEnhancer {
         let mut max_height = 0;
         let mut pixels = HashSet::new();
         for (i, line) in input.enumerate() {
+            max_height = max_height.max(i as i32);
             for (j, c) in line.chars().enumerate() {
+                max_width = max_width.max(j as i32);
                 if c == '#' {
-                    max_width = max_width.max(j as i32);
-                    max_height = max_height.max(i as i32);
                     pixels.insert(Coordinate::new(i as i32, j as i32));
                 }
             }
         }
 
-        assert!(!pixels.is_empty(), "Image must contain at least one pixel");
-
         Self {
             image: Image {
                 front_buffer: pixels,
                 width_range: 0..=max_width,
                 height_range: 0..=max_height,
                 back_buffer: HashSet::new(),
-                infinity: (
-                    *enhancement_algorithm.first().unwrap(),
-                    *enhancement_algorithm.last().unwrap(),
-                ),
+                // The infinite image starts out dark
+                background: None,
             },
             enhancement_algorithm,
         }
     }
 }
+
+#[cfg(test)]
+mod image_tests {
+    use super::*;
+
+    const SAMPLE: [&str; 7] = [
+        "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#",
+        "",
+        "#..#.",
+        "#....",
+        "##..#",
+        "..#..",
+        "..###",
+    ];
+
+    fn sample() -> ImageEnhancer {
+        ImageEnhancer::from(SAMPLE.map(String::from).to_vec())
+    }
+
+    #[test]
+    fn test_enhance_times() {
+        let mut image_enhancer = sample();
+        image_enhancer.enhance_times(2);
+        assert_eq!(image_enhancer.image.pixel_count(), 35);
+    }
+
+    #[test]
+    fn test_toggling_background() {
+        // Dark regions light up and fully lit regions go dark, so the background flips every pass
+        let mut input = SAMPLE.map(String::from).to_vec();
+        input[0].replace_range(0..1, "#");
+        input[0].replace_range(511..512, ".");
+        let mut image_enhancer = ImageEnhancer::from(input);
+
+        image_enhancer.enhance_times(1);
+        assert_eq!(image_enhancer.image.background, Some(()));
+        assert_eq!(image_enhancer.image.pixel_count(), 30);
+
+        image_enhancer.enhance_times(1);
+        assert_eq!(image_enhancer.image.background, None);
+        assert_eq!(image_enhancer.image.pixel_count(), 24);
+
+        image_enhancer.enhance_times(2);
+        assert_eq!(image_enhancer.image.pixel_count(), 57);
+    }
+
+    #[test]
+    fn test_to_ascii() {
+        let mut image_enhancer = sample();
+        assert_eq!(
+            image_enhancer.image.to_ascii(),
+            "#..#.\n#....\n##..#\n..#..\n..###"
+        );
+
+        image_enhancer.enhance_times(1);
+        assert_eq!(
+            image_enhancer.image.to_ascii(),
+            [".##.##.", "#..#.#.", "##.#..#", "####..#", ".#..##.", "..##..#", "...#.#."]
+                .join("\n")
+        );
+    }
+
+    #[cfg(feature = "image-export")]
+    #[test]
+    fn test_write_png() {
+        const PNG_MAGIC: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+        let path = std::env::temp_dir().join(format!("aoc21_day20_{}.png", std::process::id()));
+
+        let mut image_enhancer = sample();
+        image_enhancer.enhance_times(2);
+        image_enhancer.image.write_png(&path).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap()[..8], PNG_MAGIC);
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = Image {
+            width_range: 0..=-1,
+            height_range: 0..=-1,
+            front_buffer: HashSet::new(),
+            back_buffer: HashSet::new(),
+            background: None,
+        };
+        empty.write_png(&path).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap()[..8], PNG_MAGIC);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,8 +1,7 @@
 use crate::day21::board::Board;
-use crate::day21::board::PlayMode;
 use crate::day21::die::Dice;
 use crate::day21::pawn::Pawn;
-use crate::utils::day_setup::Utils;
+use crate::utils::day_setup::{AssertionError, Utils};
 use std::str::FromStr;
 
 /// Runs the Advent of Code puzzles for [Current Day](https://adventofcode.com/2021/day/21).
@@ -10,12 +9,14 @@ use std::str::FromStr;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part(part1, 1, 21, Some(428736));
-    Utils::run_part(part2, 2, 0, Some(444356092776315));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 21, Some(428736))?;
+    Utils::run_part(part2, 2, 21, Some(57328067654557))?;
+
+    Ok(())
 }
 
 fn part1(input: Vec<String>) -> u32 {
@@ -30,7 +31,7 @@ fn part2(input: Vec<String>) -> u64 {
     const SCORE: u32 = 21;
     let player1 = input[0].parse::<Pawn>().unwrap();
     let player2 = input[1].parse::<Pawn>().unwrap();
-    Board::new_quantum(player1, player2, SCORE).play(PlayMode::Recursive)
+    Board::new_quantum(player1, player2, SCORE).play()
 }
 
 mod die {
@@ -73,29 +74,13 @@ mod die {
         }
     }
 
-    pub type Possibilities = u16;
+    /// Number of universes a given sum of three rolls occurs in.
+    pub type Possibilities = u64;
     impl Dice<Quantum> {
-        const ROLL_NUM: usize = 27;
-
-        pub const fn quantum_rolls() -> [Possibilities; Self::ROLL_NUM] {
-            let mut rolls = [0; 27];
-            let mut index = 0;
-            let mut i = 1;
-            while i <= 3 {
-                let mut j = 1;
-                while j <= 3 {
-                    let mut k = 1;
-                    while k <= 3 {
-                        rolls[index] = i + j + k;
-                        index += 1;
-                        k += 1;
-                    }
-                    j += 1;
-                }
-                i += 1;
-            }
-            rolls
-        }
+        /// Each distinct sum (3..=9) of three rolls of a 3-sided die paired with how many of the
+        /// 27 universes produce it.
+        pub const QUANTUM_ROLLS: [(u16, Possibilities); 7] =
+            [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
 
         pub fn new_quantum() -> Self {
             const RANGE: RangeInclusive<u16> = 1..=3;
@@ -113,6 +98,28 @@ mod board {
     use super::{Dice, Pawn};
     use std::collections::HashMap;
 
+    /// A player's `(position, score)` in a single universe.
+    type PlayerState = (u8, u32);
+    /// Both players' states and the index of the player about to roll.
+    type GameState = (PlayerState, PlayerState, usize);
+
+    /// Final state of a deterministic game.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct GameResult {
+        /// Final scores, indexed by turn order.
+        pub scores: [u32; 2],
+        /// Total number of times the die was rolled.
+        pub rolls: u32,
+        /// Index into `scores` of the player who won.
+        pub winner: usize,
+    }
+
+    impl GameResult {
+        pub fn loser_score(&self) -> u32 {
+            self.scores[1 - self.winner]
+        }
+    }
+
     #[derive(Debug)]
     pub struct Board<D> {
         dice: Dice<D>,
@@ -120,11 +127,6 @@ mod board {
         winning_score: u32,
     }
 
-    pub enum PlayMode {
-        Recursive,
-        Iterative,
-    }
-
     impl Board<Deterministic> {
         pub fn new_deterministic(player1: Pawn, player2: Pawn, winning_score: u32) -> Self {
             Self {
@@ -134,7 +136,15 @@ mod board {
             }
         }
 
+        /// Returns the losing player's score multiplied by the number of die rolls.
         pub fn play(self) -> u32 {
+            let result = self.play_detailed();
+            result.loser_score() * result.rolls
+        }
+
+        /// Plays the game to completion and returns both final scores, the number of rolls
+        /// and which player won.
+        pub fn play_detailed(self) -> GameResult {
             let Self {
                 mut dice,
                 mut players,
@@ -150,13 +160,15 @@ mod board {
                 pawn.update_score(next_roll);
             }
 
-            let winner = if players[0].has_won(winning_score) {
-                &players[1]
-            } else {
-                &players[0]
-            };
-
-            winner.score() * dice.get_num_rolls() as u32
+            GameResult {
+                scores: [players[0].score(), players[1].score()],
+                rolls: dice.get_num_rolls() as u32,
+                winner: if players[0].has_won(winning_score) {
+                    0
+                } else {
+                    1
+                },
+            }
         }
     }
 
@@ -169,85 +181,115 @@ mod board {
             }
         }
 
-        pub fn play(self, play_mode: PlayMode) -> u64 {
-            let mut number_of_wins = [0, 0];
-
-            let Self { players, .. } = self;
+        /// Returns the number of universes won by the player who wins the most universes.
+        pub fn play(self) -> u64 {
+            let Self {
+                players: [player1, player2],
+                winning_score,
+                ..
+            } = self;
 
-            match play_mode {
-                PlayMode::Recursive => {
-                    let mut memo = HashMap::new();
-                    number_of_wins = Self::play_recursively(players, self.winning_score, &mut memo)
-                }
-                PlayMode::Iterative => {}
-            }
+            let mut memo = HashMap::new();
+            let (player1_wins, player2_wins) = Self::count_wins(
+                (player1.position(), player1.score()),
+                (player2.position(), player2.score()),
+                0,
+                winning_score,
+                &mut memo,
+            );
 
-            number_of_wins.into_iter().max().unwrap()
+            player1_wins.max(player2_wins)
         }
 
-        fn play_recursively(
-            [player1, player2]: [Pawn; 2],
-            score: u32,
-            memo: &mut HashMap<(Pawn, Pawn), [u64; 2]>,
-        ) -> [u64; 2] {
-            if player1.has_won(score) {
-                return [1, 0];
-            } else if player2.has_won(score) {
-                return [0, 1];
+        /// Counts the universes in which each player wins from the given state, where each
+        /// player is described by `(position, score)` and `turn` is the index of the player
+        /// about to roll.
+        ///
+        /// Results are memoized on the full game state, so each distinct state is only
+        /// expanded once no matter how many universes reach it.
+        fn count_wins(
+            p1: PlayerState,
+            p2: PlayerState,
+            turn: usize,
+            winning_score: u32,
+            memo: &mut HashMap<GameState, (u64, u64)>,
+        ) -> (u64, u64) {
+            if p1.1 >= winning_score {
+                return (1, 0);
+            } else if p2.1 >= winning_score {
+                return (0, 1);
             }
 
-            let state = (player1.clone(), player2.clone());
+            let state = (p1, p2, turn);
             if let Some(&result) = memo.get(&state) {
                 return result;
             }
 
-            let mut player1_wins = 0;
-            let mut player2_wins = 0;
-
-            for rolls in Dice::quantum_rolls() {
-                let mut new_player1 = player1.clone();
-                new_player1.update_score(rolls);
-
-                let [p2_wins, p1_wins] =
-                    Self::play_recursively([player2.clone(), new_player1], score, memo);
-
-                player1_wins += p1_wins;
-                player2_wins += p2_wins;
+            let mut wins = (0, 0);
+            for (roll, universes) in Dice::QUANTUM_ROLLS {
+                let (p1_wins, p2_wins) = if turn == 0 {
+                    let position = Pawn::advance(p1.0, roll);
+                    let next = (position, p1.1 + position as u32);
+                    Self::count_wins(next, p2, 1, winning_score, memo)
+                } else {
+                    let position = Pawn::advance(p2.0, roll);
+                    let next = (position, p2.1 + position as u32);
+                    Self::count_wins(p1, next, 0, winning_score, memo)
+                };
+
+                wins.0 += p1_wins * universes;
+                wins.1 += p2_wins * universes;
             }
 
-            let result = [player1_wins, player2_wins];
-            memo.insert(state, result);
-            result
+            memo.insert(state, wins);
+            wins
         }
     }
 }
 mod pawn {
     #[derive(Debug, Clone, Hash, PartialEq, Eq)]
     pub struct Pawn {
+        id: u8,
         curr_position: u8,
         score: u32,
     }
     impl Pawn {
-        pub fn new(curr_position: u8) -> Result<Self, &'static str> {
+        pub fn new(id: u8, curr_position: u8) -> Result<Self, &'static str> {
             Ok(Self {
+                id,
                 curr_position,
                 score: 0,
             })
         }
 
+        #[allow(dead_code)]
+        pub fn id(&self) -> u8 {
+            self.id
+        }
+
         pub fn score(&self) -> u32 {
             self.score
         }
 
+        pub fn position(&self) -> u8 {
+            self.curr_position
+        }
+
+        /// Returns the space reached by moving `roll` spaces forward from `position` on the
+        /// circular track of spaces 1 to 10.
+        pub fn advance(position: u8, roll: u16) -> u8 {
+            match (position as u16 + roll) % 10 {
+                0 => 10,
+                n => n as u8,
+            }
+        }
+
         pub fn has_won(&self, score: u32) -> bool {
             self.score >= score
         }
 
         pub fn update_score(&mut self, roll: u16) {
-            self.curr_position = match (self.curr_position as u16 + roll) % 10 {
-                0 => 10,
-                n => n as u8,
-            };
+            self.curr_position = Self::advance(self.curr_position, roll);
             self.score += self.curr_position as u32;
         }
     }
@@ -257,13 +299,62 @@ impl FromStr for Pawn {
 
     fn from_str(player: &str) -> Result<Self, Self::Err> {
         // Player 1 starting position: 4
-        const SKIP_LEN: usize = "Player 1 starting position: ".len();
-        let (_, num) = player.split_at(SKIP_LEN);
-        Pawn::new(num.parse().map_err(|_| {
-            "\
+        const FORMAT_ERR: &str = "\
             Format did not match format:
                  Player 1 starting position: 4\
-            "
-        })?)
+            ";
+        let (id, position) = player
+            .strip_prefix("Player ")
+            .and_then(|rest| rest.split_once(" starting position: "))
+            .ok_or(FORMAT_ERR)?;
+
+        Pawn::new(
+            id.parse().map_err(|_| FORMAT_ERR)?,
+            position.parse().map_err(|_| FORMAT_ERR)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod board_tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_pawn_ids() {
+        let pawn = "Player 2 starting position: 8".parse::<Pawn>().unwrap();
+        assert_eq!(pawn.id(), 2);
+        assert_eq!(pawn.position(), 8);
+        assert!("Player two starting position: 8".parse::<Pawn>().is_err());
+
+        let handles: Vec<_> = (0..16u8)
+            .map(|id| thread::spawn(move || (id, Pawn::new(id, 1).unwrap())))
+            .collect();
+
+        for handle in handles {
+            let (id, pawn) = handle.join().unwrap();
+            assert_eq!(pawn.id(), id);
+        }
+    }
+
+    #[test]
+    fn test_deterministic_play_detailed() {
+        let player1 = "Player 1 starting position: 4".parse::<Pawn>().unwrap();
+        let player2 = "Player 2 starting position: 8".parse::<Pawn>().unwrap();
+
+        let result = Board::new_deterministic(player1, player2, 1000).play_detailed();
+        assert_eq!(result.winner, 0);
+        assert_eq!(result.scores, [1000, 745]);
+        assert_eq!(result.rolls, 993);
+        assert_eq!(result.loser_score() * result.rolls, 739785);
+    }
+
+    #[test]
+    fn test_quantum_play() {
+        let player1 = "Player 1 starting position: 4".parse::<Pawn>().unwrap();
+        let player2 = "Player 2 starting position: 8".parse::<Pawn>().unwrap();
+
+        let wins = Board::new_quantum(player1, player2, 21).play();
+        assert_eq!(wins, 444356092776315);
     }
 }
@@ -1,26 +1,48 @@
 use std::ops::Not;
 
-use day_setup::Utils;
+use day_setup::{AssertionError, Utils};
 
 use crate::utils::day_setup;
 
-pub fn run() {
-    // run_part(function, part_num, day_num)
-    Utils::run_part(part1, 1, 3, Some(1997414));
-    Utils::run_part(part2, 2, 3, Some(1032597));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(function, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 3, Some(1997414))?;
+    Utils::run_part(part2, 2, 3, Some(1032597))?;
+
+    Ok(())
 }
-const BIT_SIZE: usize = 12;
 
 fn part1(read_file: Vec<String>) -> u64 {
-    let gamma = find_frequency(&read_file);
-    const MASK: i32 = !(-1 << BIT_SIZE);
-    let epsilon = gamma.not() & MASK;
+    let (gamma, epsilon) = gamma_epsilon(&read_file);
 
     (epsilon * gamma) as u64
 }
 
-fn find_frequency(read_file: &[String]) -> i32 {
-    let mut counter = [0i16; BIT_SIZE];
+/// Returns the number of bits in each line of the diagnostic report.
+///
+/// # Panics
+/// If the report is empty or its lines differ in length.
+fn bit_width(read_file: &[String]) -> usize {
+    let bit_size = read_file.first().expect("Empty diagnostic report").len();
+    assert!(
+        read_file.iter().all(|line| line.len() == bit_size),
+        "All lines must be {bit_size} bits wide"
+    );
+    bit_size
+}
+
+/// Returns the gamma and epsilon rates of the diagnostic report.
+fn gamma_epsilon(read_file: &[String]) -> (i32, i32) {
+    let bit_size = bit_width(read_file);
+    let gamma = find_frequency(read_file, bit_size);
+    let mask: i32 = !(-1 << bit_size);
+    let epsilon = gamma.not() & mask;
+
+    (gamma, epsilon)
+}
+
+fn find_frequency(read_file: &[String], bit_size: usize) -> i32 {
+    let mut counter = vec![0i16; bit_size];
 
     for l in read_file.iter() {
         for (idx, c) in l.chars().enumerate() {
@@ -43,46 +65,90 @@ fn find_frequency(read_file: &[String]) -> i32 {
 }
 
 fn part2(read_file: Vec<String>) -> u64 {
-    let mut oxygen = vec![];
-    let mut co2 = vec![];
-    for s in &read_file {
-        oxygen.push(s.clone());
-        co2.push(s.clone());
-    }
+    oxygen_rating(&read_file) as u64 * co2_rating(&read_file) as u64
+}
 
-    // oxygen generator rating
-    for i in (0..BIT_SIZE).rev() {
-        let oxygen_len = oxygen.len();
-        let mut acc_oxygen: Vec<String> = Vec::with_capacity(oxygen_len);
-        let co2_len = co2.len();
-        let mut acc_o2: Vec<String> = Vec::with_capacity(co2_len);
-        let freq = find_frequency(&oxygen);
-        let bit = (freq >> i) & 1;
-        if oxygen_len > 1 {
-            for x in oxygen {
-                let bytes = x.as_bytes();
-                if bit == (bytes[(BIT_SIZE - 1) - i] - b'0') as i32 {
-                    acc_oxygen.push(x);
-                }
-            }
-            oxygen = acc_oxygen;
+/// Which bit a column must have for a line to be kept while searching for a rating.
+#[derive(Debug, Clone, Copy)]
+enum BitCriteria {
+    /// Keep the most common bit, or `1` on a tie.
+    MostCommon,
+    /// Keep the least common bit, or `0` on a tie.
+    LeastCommon,
+}
+
+impl BitCriteria {
+    fn bit_to_keep(self, ones: usize, zeros: usize) -> u8 {
+        match self {
+            BitCriteria::MostCommon if ones >= zeros => b'1',
+            BitCriteria::MostCommon => b'0',
+            BitCriteria::LeastCommon if ones >= zeros => b'0',
+            BitCriteria::LeastCommon => b'1',
         }
+    }
+}
 
-        let freq = find_frequency(&co2);
-        let bit = (freq >> i) & 1;
-        if co2_len > 1 {
-            for o in co2 {
-                let bytes = o.as_bytes();
-                if bit != (bytes[(BIT_SIZE - 1) - i] - b'0') as i32 {
-                    acc_o2.push(o);
-                }
-            }
-            co2 = acc_o2;
+fn oxygen_rating(read_file: &[String]) -> i32 {
+    find_rating(read_file, BitCriteria::MostCommon)
+}
+
+fn co2_rating(read_file: &[String]) -> i32 {
+    find_rating(read_file, BitCriteria::LeastCommon)
+}
+
+/// Filters the report column by column, from the most significant bit, keeping the lines
+/// that match `criteria` until a single line is left.
+fn find_rating(read_file: &[String], criteria: BitCriteria) -> i32 {
+    let bit_size = bit_width(read_file);
+    let mut candidates = read_file.iter().collect::<Vec<_>>();
+
+    for column in 0..bit_size {
+        if candidates.len() == 1 {
+            break;
         }
+
+        let ones = candidates
+            .iter()
+            .filter(|line| line.as_bytes()[column] == b'1')
+            .count();
+        let bit = criteria.bit_to_keep(ones, candidates.len() - ones);
+        candidates.retain(|line| line.as_bytes()[column] == bit);
     }
 
-    let oxygen_rating = i32::from_str_radix(oxygen.first().unwrap(), 2).unwrap();
-    let co2_rating = i32::from_str_radix(co2.first().unwrap(), 2).unwrap();
+    assert_eq!(candidates.len(), 1, "The report has duplicate lines");
+    i32::from_str_radix(candidates[0], 2).unwrap()
+}
+
+#[cfg(test)]
+mod diagnostic_tests {
+    use super::*;
 
-    co2_rating as u64 * oxygen_rating as u64
+    const SAMPLE: [&str; 12] = [
+        "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000", "11001",
+        "00010", "01010",
+    ];
+
+    fn sample() -> Vec<String> {
+        SAMPLE.map(String::from).to_vec()
+    }
+
+    #[test]
+    fn test_five_bit_sample() {
+        assert_eq!(bit_width(&sample()), 5);
+        assert_eq!(gamma_epsilon(&sample()), (22, 9));
+        assert_eq!(part1(sample()), 198);
+        assert_eq!(part2(sample()), 230);
+    }
+
+    #[test]
+    fn test_rating_tie_breaks() {
+        // Oxygen ends on a tie between 10110 and 10111 in the last column, and CO2 on a tie
+        // between 01111 and 01010 in the third column
+        assert_eq!(oxygen_rating(&sample()), 23);
+        assert_eq!(co2_rating(&sample()), 10);
+
+        let tied = ["10".to_string(), "01".to_string()];
+        assert_eq!(oxygen_rating(&tied), 0b10);
+        assert_eq!(co2_rating(&tied), 0b01);
+    }
 }
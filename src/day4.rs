@@ -1,16 +1,27 @@
-use board::Board;
-use day_setup::Utils;
+use board::{Bingo, BitBoard};
+use day_setup::{AssertionError, Utils};
 
 use crate::utils::day_setup;
 
-pub fn run() {
-    // run_part(function, part_num, day_num)
-    Utils::run_part(part1, 1, 4, Some(35711));
-    Utils::run_part(part2, 2, 4, Some(5586));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(function, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 4, Some(35711))?;
+    Utils::run_part(part2, 2, 4, Some(5586))?;
+
+    Ok(())
+}
+
+fn part1(input: Vec<String>) -> u64 {
+    first_winner_score::<BitBoard>(input)
+}
+
+fn part2(input: Vec<String>) -> u64 {
+    last_winner_score::<BitBoard>(input)
 }
 
-fn part1(mut input: Vec<String>) -> u64 {
-    let (nums_to_draw, mut boards) = pre_processing(&mut input);
+/// Returns the score of the first board to win.
+fn first_winner_score<B: Bingo>(mut input: Vec<String>) -> u64 {
+    let (nums_to_draw, mut boards) = pre_processing::<B>(&mut input);
 
     for nums in nums_to_draw.chunks(5) {
         for num in nums {
@@ -18,13 +29,13 @@ fn part1(mut input: Vec<String>) -> u64 {
                 .iter_mut()
                 .filter_map(|board| {
                     board.mark_on_board(*num);
-                    if board.is_winner {
+                    if board.has_won() {
                         Some(board)
                     } else {
                         None
                     }
                 })
-                .collect::<Vec<&mut Board>>();
+                .collect::<Vec<&mut B>>();
 
             if let Some(found_board) = found_board.first() {
                 return found_board.sum_board_elem() * (*num as u64);
@@ -35,8 +46,9 @@ fn part1(mut input: Vec<String>) -> u64 {
     unreachable!("At least one board must win")
 }
 
-fn part2(mut input: Vec<String>) -> u64 {
-    let (nums_to_draw, mut boards) = pre_processing(&mut input);
+/// Returns the score of the last board to win.
+fn last_winner_score<B: Bingo>(mut input: Vec<String>) -> u64 {
+    let (nums_to_draw, mut boards) = pre_processing::<B>(&mut input);
 
     let mut last_board = None;
     let mut last_winning_num = None;
@@ -47,13 +59,13 @@ fn part2(mut input: Vec<String>) -> u64 {
                 .enumerate()
                 .filter_map(|(idx, board)| {
                     board.mark_on_board(*num);
-                    if board.is_winner {
+                    if board.has_won() {
                         Some((idx, board))
                     } else {
                         None
                     }
                 })
-                .collect::<Vec<(usize, &mut Board)>>();
+                .collect::<Vec<(usize, &mut B)>>();
 
             if !found_board.is_empty() {
                 last_winning_num = Some(*num);
@@ -74,7 +86,7 @@ fn part2(mut input: Vec<String>) -> u64 {
     last_board.unwrap().sum_board_elem() * (last_winning_num.unwrap() as u64)
 }
 
-fn pre_processing(input: &mut Vec<String>) -> (Vec<u8>, Vec<Board>) {
+fn pre_processing<B: Bingo>(input: &mut Vec<String>) -> (Vec<u8>, Vec<B>) {
     // Parse the numbers to draw
     let nums_to_draw: Vec<u8> = input
         .remove(0)
@@ -82,10 +94,11 @@ fn pre_processing(input: &mut Vec<String>) -> (Vec<u8>, Vec<Board>) {
         .map(|x| x.parse::<u8>().expect("Invalid number"))
         .collect();
 
-    // Parse the boards
-    let boards: Vec<Board> = input
-        .chunks(6)
-        .map(|raw_board| Board::new(&raw_board[1..]))
+    // Parse the boards, each preceded by a blank line and as tall as the first one is wide
+    let size = input[1].split_whitespace().count();
+    let boards: Vec<B> = input
+        .chunks(size + 1)
+        .map(|raw_board| B::new(&raw_board[1..]))
         .collect();
 
     (nums_to_draw, boards)
@@ -94,42 +107,57 @@ fn pre_processing(input: &mut Vec<String>) -> (Vec<u8>, Vec<Board>) {
 mod board {
     use std::fmt;
 
+    /// A square bingo board that numbers can be marked on as they are drawn.
+    pub trait Bingo {
+        /// Parses a board from its rows, which must be as many as each row is wide.
+        fn new(raw_board: &[String]) -> Self;
+        fn mark_on_board(&mut self, num: u8);
+        fn has_won(&self) -> bool;
+        /// Sum of the numbers that have not been marked.
+        fn sum_board_elem(&self) -> u64;
+    }
+
+    /// Parses the rows of an N x N board.
+    fn parse_board(raw_board: &[String]) -> Vec<Vec<u8>> {
+        let board = raw_board
+            .iter()
+            .map(|row| {
+                row.split_whitespace()
+                    .map(|x| x.parse().expect("Failed to parse number"))
+                    .collect::<Vec<u8>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            board.iter().all(|row| row.len() == board.len()),
+            "Board length mismatch"
+        );
+        board
+    }
+
+    /// Board that marks drawn numbers by overwriting them with `FOUND_MARKER`.
+    #[allow(dead_code)]
     pub struct Board {
-        board: [[u8; 5]; 5],
+        board: Vec<Vec<u8>>,
         pub is_winner: bool,
     }
 
+    #[allow(dead_code)]
     impl Board {
         const FOUND_MARKER: u8 = u8::MAX;
-        const WINNING_SUM: u16 = Board::FOUND_MARKER as u16 * 5;
 
-        pub fn new(raw_board: &[String]) -> Board {
-            assert_eq!(raw_board.len(), 5);
-            let board = raw_board
-                .iter()
-                .map(|row| {
-                    row.split_whitespace()
-                        .map(|x| x.parse().expect("Failed to parse number"))
-                        .collect::<Vec<u8>>()
-                        .try_into()
-                        .expect("Row length mismatch")
-                })
-                .collect::<Vec<[u8; 5]>>()
-                .try_into()
-                .expect("Board length mismatch");
-
-            Board {
-                board,
-                is_winner: false,
-            }
+        /// Sum of a fully marked row or column.
+        fn winning_sum(&self) -> u16 {
+            Board::FOUND_MARKER as u16 * self.board.len() as u16
         }
 
         fn is_winner(&self) -> bool {
-            let mut col_sum = [0u16; 5];
+            let winning_sum = self.winning_sum();
+            let mut col_sum = vec![0u16; self.board.len()];
 
             for row in &self.board {
                 let row_sum: u16 = row.iter().map(|&r| r as u16).sum();
-                if row_sum == Board::WINNING_SUM {
+                if row_sum == winning_sum {
                     return true;
                 }
 
@@ -139,10 +167,19 @@ mod board {
             }
 
             // Check if any column has the winning sum
-            col_sum.iter().any(|&x| x == Board::WINNING_SUM)
+            col_sum.contains(&winning_sum)
         }
+    }
 
-        pub fn mark_on_board(&mut self, num: u8) {
+    impl Bingo for Board {
+        fn new(raw_board: &[String]) -> Board {
+            Board {
+                board: parse_board(raw_board),
+                is_winner: false,
+            }
+        }
+
+        fn mark_on_board(&mut self, num: u8) {
             for row in self.board.iter_mut() {
                 if let Some(e) = row.iter_mut().find(|&&mut e| e == num) {
                     *e = Board::FOUND_MARKER;
@@ -152,7 +189,11 @@ mod board {
             self.is_winner = self.is_winner()
         }
 
-        pub fn sum_board_elem(&self) -> u64 {
+        fn has_won(&self) -> bool {
+            self.is_winner
+        }
+
+        fn sum_board_elem(&self) -> u64 {
             assert!(self.is_winner, "Cannot sum up a board that's not a winner");
 
             self.board
@@ -180,4 +221,168 @@ mod board {
             Ok(())
         }
     }
+
+    /// Board that tracks drawn numbers as a bitset, where cell `(row, col)` of an N x N board
+    /// is bit `row * N + col`.
+    pub struct BitBoard {
+        board: Vec<Vec<u8>>,
+        marked: Vec<u64>,
+        /// Masks of every row followed by every column.
+        line_masks: Vec<Vec<u64>>,
+    }
+
+    impl BitBoard {
+        /// Number of `u64` words needed to hold one bit per cell of a `size` x `size` board.
+        fn words(size: usize) -> usize {
+            (size * size).div_ceil(u64::BITS as usize)
+        }
+
+        /// Masks of the `size` lines starting at bit `i * start_step` and advancing by
+        /// `cell_step`.
+        fn line_masks(size: usize, start_step: usize, cell_step: usize) -> Vec<Vec<u64>> {
+            (0..size)
+                .map(|i| {
+                    let mut mask = vec![0; Self::words(size)];
+                    for j in 0..size {
+                        let cell = i * start_step + j * cell_step;
+                        mask[cell / 64] |= 1 << (cell % 64);
+                    }
+                    mask
+                })
+                .collect()
+        }
+
+        fn is_marked(&self, cell: usize) -> bool {
+            self.marked[cell / 64] & (1 << (cell % 64)) != 0
+        }
+    }
+
+    impl Bingo for BitBoard {
+        fn new(raw_board: &[String]) -> BitBoard {
+            let board = parse_board(raw_board);
+            let size = board.len();
+
+            let mut line_masks = Self::line_masks(size, size, 1);
+            line_masks.extend(Self::line_masks(size, 1, size));
+
+            BitBoard {
+                board,
+                marked: vec![0; Self::words(size)],
+                line_masks,
+            }
+        }
+
+        fn mark_on_board(&mut self, num: u8) {
+            if let Some(cell) = self.board.iter().flatten().position(|&e| e == num) {
+                self.marked[cell / 64] |= 1 << (cell % 64);
+            }
+        }
+
+        fn has_won(&self) -> bool {
+            self.line_masks.iter().any(|mask| {
+                mask.iter()
+                    .zip(&self.marked)
+                    .all(|(&line, &marked)| line & !marked == 0)
+            })
+        }
+
+        fn sum_board_elem(&self) -> u64 {
+            assert!(self.has_won(), "Cannot sum up a board that's not a winner");
+
+            self.board
+                .iter()
+                .flatten()
+                .enumerate()
+                .filter(|&(cell, _)| !self.is_marked(cell))
+                .map(|(_, &e)| e as u64)
+                .sum()
+        }
+    }
+}
+
+#[cfg(test)]
+mod board_tests {
+    use super::*;
+    use board::Board;
+
+    const SAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    fn sample() -> Vec<String> {
+        SAMPLE.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_boards_agree() {
+        assert_eq!(first_winner_score::<Board>(sample()), 4512);
+        assert_eq!(first_winner_score::<BitBoard>(sample()), 4512);
+
+        assert_eq!(last_winner_score::<Board>(sample()), 1924);
+        assert_eq!(last_winner_score::<BitBoard>(sample()), 1924);
+    }
+
+    #[test]
+    fn test_small_board_column_win() {
+        let input = "\
+5,2,10,8,1
+
+1 2 3
+4 5 6
+7 8 9
+
+10 11 12
+13 14 15
+16 17 18";
+        let sample = || input.lines().map(String::from).collect::<Vec<_>>();
+
+        // Column [2, 5, 8] completes on 8, leaving 1 + 3 + 4 + 6 + 7 + 9 unmarked
+        assert_eq!(first_winner_score::<Board>(sample()), 30 * 8);
+        assert_eq!(first_winner_score::<BitBoard>(sample()), 30 * 8);
+    }
+
+    #[test]
+    fn test_large_board() {
+        // A 12 x 12 board holding 0..144 row by row, which needs more than 128 bits
+        let rows = (0..12u8)
+            .map(|row| {
+                (0..12u8)
+                    .map(|col| (row * 12 + col).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>();
+        // Drawing the last column from the bottom up only completes it on 11
+        let draws = (0..12u8)
+            .rev()
+            .map(|row| (row * 12 + 11).to_string())
+            .collect::<Vec<_>>();
+        let sample = || {
+            let mut input = vec![draws.join(","), String::new()];
+            input.extend(rows.iter().cloned());
+            input
+        };
+
+        let column_sum = (0..12).map(|row| row * 12 + 11).sum::<u64>();
+        let expected = ((0..144).sum::<u64>() - column_sum) * 11;
+        assert_eq!(first_winner_score::<Board>(sample()), expected);
+        assert_eq!(first_winner_score::<BitBoard>(sample()), expected);
+    }
 }
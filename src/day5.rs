@@ -1,15 +1,15 @@
-use std::iter::zip;
+use day_setup::{AssertionError, Utils};
 
-use day_setup::Utils;
-
-use crate::day5::diagram::Diagram;
+use crate::day5::diagram::SparseDiagram;
 use crate::day5::lines::Line;
 use crate::utils::day_setup;
 
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part(part1, 1, 5, Some(4993));
-    Utils::run_part(part2, 2, 5, Some(21101));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 5, Some(4993))?;
+    Utils::run_part(part2, 2, 5, Some(21101))?;
+
+    Ok(())
 }
 
 /// Part 1 solution: considers only horizontal and vertical lines.
@@ -20,9 +20,9 @@ pub fn run() {
 /// # Returns
 /// * `u64` - The number of points where at least two lines overlap.
 fn part1(input: Vec<String>) -> u64 {
-    let mut diagram = Diagram::new();
+    let mut diagram = SparseDiagram::new();
     for line in input {
-        diagram.draw_line(Line::new(extract_ranges(line)), |_, _| {});
+        diagram.draw_line(Line::new(extract_ranges(line)), false);
     }
     diagram.num_of_overlap()
 }
@@ -35,18 +35,9 @@ fn part1(input: Vec<String>) -> u64 {
 /// # Returns
 /// * `u64` - The number of points where at least two lines overlap.
 fn part2(input: Vec<String>) -> u64 {
-    let mut diagram = Diagram::new();
+    let mut diagram = SparseDiagram::new();
     for line in input {
-        diagram.draw_line(
-            Line::new(extract_ranges(line)),
-            |diagram: &mut Diagram, line: Line| {
-                if line.is_diagonal {
-                    for (x, y) in zip(line.x_range(), line.y_range()) {
-                        diagram.place_at(x, y);
-                    }
-                }
-            },
-        );
+        diagram.draw_line(Line::new(extract_ranges(line)), true);
     }
 
     diagram.num_of_overlap()
@@ -103,47 +94,50 @@ mod lines {
             }
         }
 
-        /// Returns the x range as a vector, reversed if necessary.
+        /// Returns every point on the line segment, from its start to its end inclusive.
         ///
-        /// # Returns
-        /// * `Vec<usize>` - The x range as a vector.
-        pub fn x_range(&self) -> Vec<usize> {
-            let start = self.x_range.0;
-            let end = self.x_range.1;
-            if start > end {
-                (end..=start).rev().collect()
-            } else {
-                (start..=end).collect()
-            }
-        }
-
-        /// Returns the y range as a vector, reversed if necessary.
+        /// Lines that are neither perpendicular nor at 45° yield no points.
         ///
         /// # Returns
-        /// * `Vec<usize>` - The y range as a vector.
-        pub fn y_range(&self) -> Vec<usize> {
-            let start = self.y_range.0;
-            let end = self.y_range.1;
-            if start > end {
-                (end..=start).rev().collect()
+        /// * `impl Iterator<Item = (usize, usize)>` - The (x, y) points on the line.
+        pub fn points(&self) -> impl Iterator<Item = (usize, usize)> {
+            let (x1, x2) = self.x_range;
+            let (y1, y2) = self.y_range;
+            let dx = (x2 as isize - x1 as isize).signum();
+            let dy = (y2 as isize - y1 as isize).signum();
+            let len = if self.is_perpendicular || self.is_diagonal {
+                x1.abs_diff(x2).max(y1.abs_diff(y2)) + 1
             } else {
-                (start..=end).collect()
-            }
+                0
+            };
+
+            (0..len as isize).map(move |step| {
+                (
+                    (x1 as isize + dx * step) as usize,
+                    (y1 as isize + dy * step) as usize,
+                )
+            })
         }
     }
 }
 
 mod diagram {
     use crate::day5::lines::Line;
+    use std::collections::HashMap;
 
     const ARRAY_SIZE: usize = 1000;
 
     /// Represents the diagram where lines are drawn and overlaps are calculated.
+    ///
+    /// Backed by a fixed `ARRAY_SIZE` x `ARRAY_SIZE` board, see [`SparseDiagram`] for inputs
+    /// that are larger or sparse.
+    #[allow(dead_code)]
     pub struct Diagram {
         num_of_overlap: u32,
         board: Box<[[u16; ARRAY_SIZE]; ARRAY_SIZE]>,
     }
 
+    #[allow(dead_code)]
     impl Diagram {
         /// Creates a new, empty `Diagram`.
         ///
@@ -161,24 +155,18 @@ mod diagram {
             }
         }
 
-        /// Draws a line on the diagram, with optional extra conditions.
-        /// DEFAULT CONDITION: If line `is_perpendicular`
+        /// Draws a line on the diagram if it is perpendicular, or diagonal when
+        /// `include_diagonals` is set.
         ///
         /// # Arguments
         /// * `line` - The line to be drawn.
-        /// * `extra_draw_conditions` - Additional drawing logic to be applied.
-        pub fn draw_line<F>(&mut self, line: Line, mut extra_draw_conditions: F)
-        where
-            F: FnMut(&mut Diagram, Line),
-        {
-            if line.is_perpendicular {
-                for x in line.x_range() {
-                    for y in line.y_range() {
-                        self.place_at(x, y);
-                    }
+        /// * `include_diagonals` - Whether 45° diagonal lines are drawn.
+        pub fn draw_line(&mut self, line: Line, include_diagonals: bool) {
+            if line.is_perpendicular || (include_diagonals && line.is_diagonal) {
+                for (x, y) in line.points() {
+                    self.place_at(x, y);
                 }
             }
-            extra_draw_conditions(self, line);
         }
 
         /// Places an element at the specified (x, y) position on the board.
@@ -210,4 +198,109 @@ mod diagram {
             self.num_of_overlap as u64
         }
     }
+
+    /// Represents the diagram where lines are drawn and overlaps are calculated.
+    ///
+    /// Only the points that lines pass through are stored, so the diagram grows to fit any
+    /// coordinates it is given.
+    pub struct SparseDiagram {
+        num_of_overlap: u32,
+        board: HashMap<(usize, usize), u16>,
+    }
+
+    impl SparseDiagram {
+        /// Creates a new, empty `SparseDiagram`.
+        ///
+        /// # Returns
+        /// * `SparseDiagram` - The created diagram.
+        pub fn new() -> SparseDiagram {
+            SparseDiagram {
+                num_of_overlap: 0,
+                board: HashMap::new(),
+            }
+        }
+
+        /// Draws a line on the diagram if it is perpendicular, or diagonal when
+        /// `include_diagonals` is set.
+        ///
+        /// # Arguments
+        /// * `line` - The line to be drawn.
+        /// * `include_diagonals` - Whether 45° diagonal lines are drawn.
+        pub fn draw_line(&mut self, line: Line, include_diagonals: bool) {
+            if line.is_perpendicular || (include_diagonals && line.is_diagonal) {
+                for (x, y) in line.points() {
+                    self.place_at(x, y);
+                }
+            }
+        }
+
+        /// Places an element at the specified (x, y) position on the board.
+        ///
+        /// This function increments the count at the given position by 1.
+        /// If the new count at this position is 2, it increments the `num_of_overlap` counter.
+        ///
+        /// # Parameters
+        ///
+        /// - `x`: The x-coordinate (column index) of the position.
+        /// - `y`: The y-coordinate (row index) of the position.
+        pub fn place_at(&mut self, x: usize, y: usize) {
+            let count = self.board.entry((x, y)).or_insert(0);
+            *count += 1;
+            if *count == 2 {
+                self.num_of_overlap += 1;
+            }
+        }
+
+        /// Calculates the number of points where at least two lines overlap.
+        ///
+        /// # Returns
+        /// * `u64` - The number of overlapping points.
+        pub fn num_of_overlap(&self) -> u64 {
+            self.num_of_overlap as u64
+        }
+    }
+}
+
+#[cfg(test)]
+mod diagram_tests {
+    use super::*;
+    use crate::day5::diagram::Diagram;
+
+    const FAR_LINES: [&str; 3] = [
+        "1500,0 -> 1500,2000",
+        "0,1999 -> 3000,1999",
+        "1400,1900 -> 1600,2100",
+    ];
+
+    #[test]
+    fn test_sparse_beyond_dense_bounds() {
+        let input = FAR_LINES.map(String::from).to_vec();
+        assert_eq!(part1(input.clone()), 1);
+        assert_eq!(part2(input), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dense_rejects_out_of_bounds() {
+        let mut diagram = Diagram::new();
+        diagram.draw_line(Line::new(extract_ranges(FAR_LINES[0].to_string())), false);
+    }
+
+    #[test]
+    fn test_line_points() {
+        let diagonal = Line::new(extract_ranges("9,7 -> 7,9".to_string()));
+        assert_eq!(
+            diagonal.points().collect::<Vec<_>>(),
+            [(9, 7), (8, 8), (7, 9)]
+        );
+
+        let vertical = Line::new(extract_ranges("1,3 -> 1,1".to_string()));
+        assert_eq!(
+            vertical.points().collect::<Vec<_>>(),
+            [(1, 3), (1, 2), (1, 1)]
+        );
+
+        let skewed = Line::new(extract_ranges("0,0 -> 2,1".to_string()));
+        assert_eq!(skewed.points().count(), 0);
+    }
 }
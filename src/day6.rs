@@ -1,4 +1,4 @@
-use day_setup::Utils;
+use day_setup::{AssertionError, Utils};
 
 use crate::day6::lantern_fish::LanternFishList;
 use crate::utils::day_setup;
@@ -8,12 +8,14 @@ use crate::utils::day_setup;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part(part1, 1, 6, Some(396210));
-    Utils::run_part(part2, 2, 6, Some(1770823541496));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 6, Some(396210))?;
+    Utils::run_part(part2, 2, 6, Some(1770823541496))?;
+
+    Ok(())
 }
 
 fn part1(input: Vec<LanternFishList>) -> u64 {
@@ -24,7 +26,7 @@ fn part1(input: Vec<LanternFishList>) -> u64 {
     );
     const MAX_DAYS_TO_SIMULATE: u16 = 80;
 
-    simulate_days(input.first().unwrap(), MAX_DAYS_TO_SIMULATE)
+    input.first().unwrap().simulate(MAX_DAYS_TO_SIMULATE)
 }
 
 fn part2(input: Vec<LanternFishList>) -> u64 {
@@ -36,34 +38,11 @@ fn part2(input: Vec<LanternFishList>) -> u64 {
 
     const MAX_DAYS_TO_SIMULATE: u16 = 256;
 
-    simulate_days(input.first().unwrap(), MAX_DAYS_TO_SIMULATE)
-}
-
-fn simulate_days(lantern_fish_list: &LanternFishList, max_days_to_simulate: u16) -> u64 {
-    let mut lantern_fishes_index = [0u64; 9];
-
-    lantern_fish_list.fishes.iter().for_each(|lantern_fish| {
-        lantern_fishes_index[lantern_fish.days_left_before_birth as usize] += 1;
-    });
-
-    for _ in 0..max_days_to_simulate {
-        // Find the number of new fishes to be born
-        let new_fishes = lantern_fishes_index[0];
-
-        // Decrease the number of days left till birth for all fishes
-        lantern_fishes_index.rotate_left(1);
-
-        // Reset timer for all fishes which have given birth
-        lantern_fishes_index[6] += new_fishes;
-
-        // Give birth to new fishes
-        lantern_fishes_index[8] = new_fishes;
-    }
-
-    lantern_fishes_index.iter().sum()
+    input.first().unwrap().simulate(MAX_DAYS_TO_SIMULATE)
 }
 
 mod lantern_fish {
+    use crate::utils::day_setup::Utils;
     use std::str::FromStr;
 
     const DEFAULT_DAYS_TO_SIMULATE: u8 = 8;
@@ -74,16 +53,6 @@ mod lantern_fish {
         pub days_left_before_birth: u8,
     }
 
-    impl LanternFish {
-        pub fn new(days_left_before_birth: &str) -> LanternFish {
-            LanternFish {
-                days_left_before_birth: days_left_before_birth
-                    .parse::<u8>()
-                    .expect("Could not parse num of days"),
-            }
-        }
-    }
-
     impl Default for LanternFish {
         #[inline(always)]
         fn default() -> Self {
@@ -97,17 +66,75 @@ mod lantern_fish {
         pub fishes: Box<[LanternFish]>,
     }
 
+    impl LanternFishList {
+        /// Returns the number of lantern fishes after `days` have passed.
+        pub fn simulate(&self, days: u16) -> u64 {
+            self.simulate_breakdown(days).iter().sum()
+        }
+
+        /// Returns the number of lantern fishes with each number of days left before birth
+        /// (0 to 8) after `days` have passed.
+        pub fn simulate_breakdown(&self, days: u16) -> [u64; 9] {
+            let mut lantern_fishes_index = [0u64; 9];
+
+            self.fishes.iter().for_each(|lantern_fish| {
+                lantern_fishes_index[lantern_fish.days_left_before_birth as usize] += 1;
+            });
+
+            for _ in 0..days {
+                // Find the number of new fishes to be born
+                let new_fishes = lantern_fishes_index[0];
+
+                // Decrease the number of days left till birth for all fishes
+                lantern_fishes_index.rotate_left(1);
+
+                // Reset timer for all fishes which have given birth
+                lantern_fishes_index[6] += new_fishes;
+
+                // Give birth to new fishes
+                lantern_fishes_index[8] = new_fishes;
+            }
+
+            lantern_fishes_index
+        }
+    }
+
     impl FromStr for LanternFishList {
-        type Err = ();
+        type Err = String;
 
         fn from_str(input: &str) -> Result<Self, Self::Err> {
             Ok(LanternFishList {
-                fishes: input
-                    .split(',')
-                    .map(LanternFish::new)
+                fishes: Utils::parse_csv::<u8>(input)?
+                    .into_iter()
+                    .map(|days_left_before_birth| LanternFish {
+                        days_left_before_birth,
+                    })
                     .collect::<Vec<LanternFish>>()
                     .into_boxed_slice(),
             })
         }
     }
 }
+
+#[cfg(test)]
+mod lantern_fish_tests {
+    use super::*;
+
+    fn sample() -> LanternFishList {
+        "3,4,3,1,2".parse().unwrap()
+    }
+
+    #[test]
+    fn test_simulate() {
+        assert_eq!(sample().simulate(18), 26);
+        assert_eq!(sample().simulate(80), 5934);
+        assert_eq!(sample().simulate(256), 26984457539);
+    }
+
+    #[test]
+    fn test_simulate_breakdown() {
+        assert_eq!(sample().simulate_breakdown(0), [0, 1, 1, 2, 1, 0, 0, 0, 0]);
+        // 0,1,0,5,6,7,8
+        assert_eq!(sample().simulate_breakdown(3), [2, 1, 0, 0, 0, 1, 1, 1, 1]);
+    }
+}
@@ -1,4 +1,4 @@
-use day_setup::Utils;
+use day_setup::{AssertionError, Utils};
 use std::str::FromStr;
 
 use crate::utils::day_setup;
@@ -8,12 +8,14 @@ use crate::utils::day_setup;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part(part1, 1, 7, Some(356992));
-    Utils::run_part(part2, 2, 7, Some(101268110));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 7, Some(356992))?;
+    Utils::run_part(part2, 2, 7, Some(101268110))?;
+
+    Ok(())
 }
 
 /// Solves part 1 of the puzzle.
@@ -24,9 +26,8 @@ pub fn run() {
 /// # Returns
 /// * `u64` - The minimum fuel cost.
 fn part1(input: Vec<CrabPositions>) -> u64 {
-    let adjustment_function = |distance: u64| distance;
     assert_eq!(input.len(), 1, "Expected only one crab position");
-    min_fuel_cost(input.first().unwrap(), adjustment_function)
+    input.first().unwrap().min_fuel_linear()
 }
 
 /// Solves part 2 of the puzzle.
@@ -37,16 +38,18 @@ fn part1(input: Vec<CrabPositions>) -> u64 {
 /// # Returns
 /// * `u64` - The minimum fuel cost.
 fn part2(input: Vec<CrabPositions>) -> u64 {
-    let adjustment_function = |distance: u64| {
-        let n = distance + 1;
-        // Σ n Whole Numbers formula:
-        (n * (n - 1)) / 2
-    };
     assert_eq!(input.len(), 1, "Expected only one crab position");
-    min_fuel_cost(input.first().unwrap(), adjustment_function)
+    input.first().unwrap().min_fuel_triangular()
+}
+
+/// Fuel cost of moving a crab `distance` steps when each step costs one more than the last.
+fn triangular_cost(distance: u64) -> u64 {
+    // 1 + 2 + ... + distance
+    distance * (distance + 1) / 2
 }
 
-/// Calculates the minimum fuel cost to align all crab positions.
+/// Calculates the minimum fuel cost to align all crab positions by trying every position from 0 up
+/// to the furthest crab.
 ///
 /// # Arguments
 /// * `horizontal_positions` - A reference to `CrabPositions`.
@@ -54,9 +57,17 @@ fn part2(input: Vec<CrabPositions>) -> u64 {
 ///
 /// # Returns
 /// * `u64` - The minimum fuel cost.
+#[allow(dead_code)]
 fn min_fuel_cost(horizontal_positions: &CrabPositions, adjustment_function: fn(u64) -> u64) -> u64 {
-    (0..horizontal_positions.positions.len())
-        .map(|idx| horizontal_positions.find_distance(idx + 1, adjustment_function))
+    let max_position = horizontal_positions
+        .positions
+        .iter()
+        .max()
+        .copied()
+        .unwrap_or(0);
+
+    (0..=max_position as usize)
+        .map(|pos| horizontal_positions.find_distance(pos, adjustment_function))
         .min()
         .unwrap_or(0)
 }
@@ -69,6 +80,36 @@ struct CrabPositions {
 }
 
 impl CrabPositions {
+    /// Minimum fuel cost when each step costs 1, found by aligning to the median position.
+    ///
+    /// # Returns
+    /// * `u64` - The minimum fuel cost.
+    pub fn min_fuel_linear(&self) -> u64 {
+        let mut sorted = self.positions.to_vec();
+        sorted.sort_unstable();
+        let median = sorted[sorted.len() / 2];
+
+        self.find_distance(median as usize, |distance| distance)
+    }
+
+    /// Minimum fuel cost when each step costs one more than the last.
+    ///
+    /// The optimal position lies within half a step of the mean, so only the positions
+    /// either side of it are checked.
+    ///
+    /// # Returns
+    /// * `u64` - The minimum fuel cost.
+    pub fn min_fuel_triangular(&self) -> u64 {
+        let sum = self.positions.iter().map(|&e| e as u64).sum::<u64>();
+        let floor_mean = (sum / self.positions.len() as u64) as usize;
+
+        [floor_mean, floor_mean + 1]
+            .into_iter()
+            .map(|pos| self.find_distance(pos, triangular_cost))
+            .min()
+            .unwrap()
+    }
+
     /// Finds the total distance for aligning all crab positions to a specific position.
     ///
     /// # Arguments
@@ -89,7 +130,7 @@ impl CrabPositions {
 }
 
 impl FromStr for CrabPositions {
-    type Err = &'static str;
+    type Err = String;
 
     /// Parses a string into `CrabPositions`.
     ///
@@ -100,11 +141,51 @@ impl FromStr for CrabPositions {
     /// * `Result<CrabPositions, Self::Err>` - A result containing `CrabPositions` or an error.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(CrabPositions {
-            positions: s
-                .split(',')
-                .map(|str| str.parse::<u32>().expect("Could not parse crab positions"))
-                .collect::<Vec<u32>>()
-                .into_boxed_slice(),
+            positions: Utils::parse_csv::<u32>(s)?.into_boxed_slice(),
         })
     }
 }
+
+#[cfg(test)]
+mod crab_tests {
+    use super::*;
+
+    fn sample() -> CrabPositions {
+        "16,1,2,0,4,2,7,1,2,14".parse().unwrap()
+    }
+
+    #[test]
+    fn test_min_fuel() {
+        assert_eq!(sample().min_fuel_linear(), 37);
+        assert_eq!(sample().min_fuel_triangular(), 168);
+    }
+
+    #[test]
+    fn test_triangular_cost() {
+        let costs = (0..=5).map(triangular_cost).collect::<Vec<_>>();
+        assert_eq!(costs, [0, 1, 3, 6, 10, 15]);
+        assert_eq!(triangular_cost(u32::MAX as u64), 9223372034707292160);
+    }
+
+    #[test]
+    fn test_min_fuel_matches_brute_force() {
+        // The best positions of the last two lie at 0 and well beyond the number of crabs
+        for crabs in [
+            sample(),
+            "0,0,0,9".parse().unwrap(),
+            "100,100".parse().unwrap(),
+        ] {
+            assert_eq!(
+                crabs.min_fuel_linear(),
+                min_fuel_cost(&crabs, |distance| distance),
+                "{crabs:?}"
+            );
+            assert_eq!(
+                crabs.min_fuel_triangular(),
+                min_fuel_cost(&crabs, triangular_cost),
+                "{crabs:?}"
+            );
+        }
+        assert_eq!(min_fuel_cost(&"100,100".parse().unwrap(), |d| d), 0);
+    }
+}
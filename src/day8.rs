@@ -1,6 +1,6 @@
-use crate::day8::decoder::SignalDecoder;
+use crate::day8::decoder::{DecodeError, SignalDecoder};
 use crate::utils::day_setup;
-use day_setup::Utils;
+use day_setup::{AssertionError, Utils};
 use std::str::FromStr;
 
 /// Runs the Advent of Code puzzles for [Current Day](https://adventofcode.com/2021/day/8).
@@ -8,12 +8,14 @@ use std::str::FromStr;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part(part1, 1, 8, Some(344));
-    Utils::run_part(part2, 2, 8, Some(1048410));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 8, Some(344))?;
+    Utils::run_part(part2, 2, 8, Some(1048410))?;
+
+    Ok(())
 }
 
 fn part1(segment_list: Vec<SignalContext>) -> u64 {
@@ -27,7 +29,7 @@ fn part1(segment_list: Vec<SignalContext>) -> u64 {
                 .iter()
                 .filter(|digit| {
                     let digit_len = digit.len() as u8;
-                    SEGMENTS_COUNT.iter().any(|&count| count == digit_len)
+                    SEGMENTS_COUNT.contains(&digit_len)
                 })
                 .count() as u64
         })
@@ -41,6 +43,7 @@ fn part2(signal_contexts: Vec<SignalContext>) -> u64 {
             // [5,3,8,9] => 5389
             signal_context
                 .decode()
+                .unwrap()
                 .into_iter()
                 .fold(0, |mut acc, digit| {
                     acc *= 10;
@@ -110,13 +113,6 @@ enum ClockNumber {
     Nine = 0b1111011,
 }
 
-macro_rules! bit_index_turned_on {
-    ($clock_number: expr) => {{
-        const N: usize = $clock_number.count_segments() as usize;
-        $clock_number.bit_index_turned_on::<{ N }>()
-    }};
-}
-
 impl ClockNumber {
     /// Counts the number of segments that are turned on for the given `ClockNumber`.
     ///
@@ -144,37 +140,31 @@ impl ClockNumber {
         ]
     }
 
-    /// Returns an array of bit indices that are turned on for the given `ClockNumber`.
-    ///
-    /// # Arguments
-    /// * `self` - The `ClockNumber` instance.
-    ///
-    /// # Returns
-    /// An array of bit indices that are turned on.
+    /// Every digit, ordered by its integer representation.
+    const ALL: [ClockNumber; 10] = [
+        ClockNumber::Zero,
+        ClockNumber::One,
+        ClockNumber::Two,
+        ClockNumber::Three,
+        ClockNumber::Four,
+        ClockNumber::Five,
+        ClockNumber::Six,
+        ClockNumber::Seven,
+        ClockNumber::Eight,
+        ClockNumber::Nine,
+    ];
+
+    /// Returns the `ClockNumber` whose segments are exactly those set in `mask`, if any.
     ///
     /// # Example
     /// ```
-    /// let clock_number = ClockNumber::Three;
-    /// let bit_indices = clock_number.bit_index_turned_on::<5>();
-    /// assert_eq!(bit_indices, [0, 2, 3, 5, 6]);
+    /// assert_eq!(ClockNumber::from_mask(0b1011011), Some(ClockNumber::Three));
+    /// assert_eq!(ClockNumber::from_mask(0b0000001), None);
     /// ```
-    const fn bit_index_turned_on<const N: usize>(self) -> [u8; N] {
-        let mut bit_index = [0; N];
-        let mut mask = self as u8;
-        const BIT_COUNT: i32 = 7;
-
-        let mut idx = (N - 1) as isize;
-        let mut bit_count_idx = BIT_COUNT - 1;
-        while mask != 0 {
-            if (mask & 1) == 1 {
-                bit_index[idx as usize] = bit_count_idx as u8;
-                idx -= 1;
-            }
-            mask >>= 1;
-            bit_count_idx -= 1;
-        }
-
-        bit_index
+    fn from_mask(mask: u8) -> Option<ClockNumber> {
+        Self::ALL
+            .into_iter()
+            .find(|&clock_number| clock_number as u8 == mask)
     }
 
     /// Returns the integer representation of the `ClockNumber`.
@@ -216,11 +206,12 @@ struct SignalContext {
 impl SignalContext {
     /// Decodes the output values of the signal context.
     ///
-    /// This function initializes a `SignalDecoder` with the unique signal patterns,
-    /// decodes the unique signal patterns, and then decodes the output values.
+    /// This function resolves the wiring of a `SignalDecoder` from the unique signal patterns,
+    /// and then decodes the output values with it.
     ///
     /// # Returns
-    /// An array of 4 decoded output values as `u16`.
+    /// An array of 4 decoded output values as `u16`, or the `DecodeError` naming the pattern
+    /// or output that could not be decoded.
     ///
     /// # Example
     /// ```
@@ -229,24 +220,30 @@ impl SignalContext {
     ///     output_value: [String::from("ef"), String::from("gh"), ...],
     /// };
     /// let decoded_output = signal_context.decode();
-    /// assert_eq!(decoded_output, [1, 2, 3, 4]);
+    /// assert_eq!(decoded_output, Ok([1, 2, 3, 4]));
     /// ```
-    fn decode(&self) -> [u16; 4] {
-        let mut decoder_context: SignalDecoder = SignalDecoder::new(&self.unique_signal_patterns);
-        decoder_context.decode_unique_signal_patterns();
-
-        let mut decoded_output: [u16; 4] = [0; 4];
+    fn decode(&self) -> Result<[u16; 4], DecodeError> {
+        SignalDecoder::new(&self.unique_signal_patterns, &self.output_value)?.decode()
+    }
 
-        for (idx, output) in self.output_value.iter().enumerate() {
-            decoded_output[idx] = decoder_context.decode_output(output).int_repr() as u16;
-        }
+    /// Splits `input` on whitespace into exactly `N` strings.
+    ///
+    /// # Errors
+    /// Names the `kind` of token and how many were found if there are not exactly `N`.
+    fn parse_exact<const N: usize>(input: &str, kind: &str) -> Result<[String; N], String> {
+        let tokens = input
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<_>>();
 
-        decoded_output
+        tokens
+            .try_into()
+            .map_err(|tokens: Vec<String>| format!("Expected {N} {kind}, found {}", tokens.len()))
     }
 }
 
 impl FromStr for SignalContext {
-    type Err = &'static str;
+    type Err = String;
 
     /// Parses a string input to create a `SignalContext` instance.
     ///
@@ -259,10 +256,8 @@ impl FromStr for SignalContext {
     ///
     /// # Returns
     /// A `Result` containing the `SignalContext` instance if parsing is successful,
-    /// or a static string slice error message if parsing fails.
-    ///
-    /// # Panics
-    /// Panics if the input string does not contain a '|' separator.
+    /// or an error message if the input has no '|' separator or does not contain exactly
+    /// 10 signal patterns and 4 output values.
     ///
     /// # Example
     /// ```
@@ -272,409 +267,256 @@ impl FromStr for SignalContext {
     /// assert_eq!(signal_context.output_value.len(), 4);
     /// ```
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut signal_patterns: [String; 10] = core::array::from_fn(|_| String::new());
-        let mut output: [String; 4] = core::array::from_fn(|_| String::new());
-        let (unique_signal_patterns, output_value) =
-            input.split_once('|').expect("Invalid input format");
-
-        unique_signal_patterns
-            .split_whitespace()
-            .enumerate()
-            .for_each(|(idx, input)| {
-                signal_patterns[idx] = input.to_string();
-            });
-
-        output_value
-            .split_whitespace()
-            .enumerate()
-            .for_each(|(idx, input)| {
-                output[idx] = input.to_string();
-            });
+        let (unique_signal_patterns, output_value) = input
+            .split_once('|')
+            .ok_or_else(|| format!("Missing '|' separator in '{input}'"))?;
 
         Ok(SignalContext {
-            unique_signal_patterns: signal_patterns,
-            output_value: output,
+            unique_signal_patterns: Self::parse_exact(unique_signal_patterns, "signal patterns")?,
+            output_value: Self::parse_exact(output_value, "output values")?,
         })
     }
 }
 
 mod decoder {
     use crate::day8::ClockNumber;
-    use std::collections::HashSet;
-
-    /// Represents a digit in a 7-segment display.
-    ///
-    /// # Fields
-    /// * `number` - The `ClockNumber` corresponding to the digit.
-    /// * `segment_chars` - A set of characters representing the segments that are turned on.
-    ///
-    /// # Example
-    /// ```
-    /// let digit = Digits {
-    ///     number: ClockNumber::Three,
-    ///     segment_chars: HashSet::from(['a', 'b', 'c', 'd', 'e']),
-    /// };
-    /// assert_eq!(digit.number, ClockNumber::Three);
-    /// assert!(digit.segment_chars.contains(&'a'));
-    /// ```
-    #[derive(Debug)]
-    pub struct Digits {
-        number: ClockNumber,
-        segment_chars: HashSet<char>,
+    use std::fmt;
+
+    const SEGMENT_COUNT: usize = 7;
+
+    /// The reason a signal could not be decoded, naming the offending pattern.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum DecodeError {
+        /// The pattern uses a wire outside `a` to `g`, or uses the same wire twice.
+        InvalidWires(String),
+        /// No wiring maps this signal pattern, together with every pattern before it, to digits.
+        InconsistentPattern(String),
+        /// The output does not light up a digit under the resolved wiring.
+        UnknownOutput(String),
     }
 
-    impl Digits {
-        pub fn is_decoded(&self) -> bool {
-            !self.segment_chars.is_empty()
+    impl fmt::Display for DecodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                DecodeError::InvalidWires(pattern) => {
+                    write!(f, "Pattern '{pattern}' is not a set of distinct wires a to g")
+                }
+                DecodeError::InconsistentPattern(pattern) => write!(
+                    f,
+                    "Signal pattern '{pattern}' cannot be wired consistently with the patterns before it"
+                ),
+                DecodeError::UnknownOutput(output) => {
+                    write!(f, "Output '{output}' not found in signal patterns")
+                }
+            }
         }
     }
 
+    impl std::error::Error for DecodeError {}
+
     /// A struct for decoding signals in a 7-segment display.
     ///
-    /// The `SignalDecoder` struct is used to decode the segments and digits of a 7-segment display
-    /// based on provided signal patterns.
+    /// The wiring is found by brute force: every one of the 7! ways of assigning the signal
+    /// wires (a to g) to the segments is tried until one turns all ten signal patterns into
+    /// valid digits.
     #[derive(Debug)]
     pub struct SignalDecoder<'ctx> {
-        /// Array to store the decoded segments (a to g) of the 7-segment display.
-        pub decoded_segments: [char; 7],
-        /// Array to store the decoded digits (0 to 9) with their corresponding segments.
-        /// The core segments are [One, Seven, Four, Eight].
-        pub decoded_digits: [Digits; 10],
-        /// Reference to the signal patterns provided as input.
-        pub signal_patterns: &'ctx [String; 10],
-        /// Array to store the core segments with their encoded string patterns.
-        /// The core segments are [One, Four, Seven, Eight].
-        decoded_core_segment: [(ClockNumber, &'ctx str); 4],
+        /// Array to store the decoded segments (0 to 6) of the 7-segment display, i.e. the
+        /// signal wire driving each segment.
+        decoded_segments: [char; SEGMENT_COUNT],
+        /// Reference to the output values to decode.
+        pub output_value: &'ctx [String; 4],
     }
 
     impl<'ctx> SignalDecoder<'ctx> {
-        pub fn decode_output(&self, output: &str) -> ClockNumber {
-            let output = output.chars().collect::<HashSet<_>>();
-            for decoded_digit in &self.decoded_digits {
-                if decoded_digit.segment_chars.eq(&output) {
-                    return decoded_digit.number;
-                }
+        /// Resolves the wiring of the display from its signal patterns.
+        ///
+        /// # Errors
+        /// If a pattern is not made of distinct wires, or no wiring maps every pattern to a digit.
+        pub fn new(
+            signal_patterns: &'ctx [String; 10],
+            output_value: &'ctx [String; 4],
+        ) -> Result<SignalDecoder<'ctx>, DecodeError> {
+            for pattern in signal_patterns.iter().chain(output_value) {
+                Self::validate_wires(pattern)?;
             }
-            panic!("Output '{:?}' not found in signal patterns", output);
-        }
 
-        pub fn decode_unique_signal_patterns(&mut self) {
-            // Decode the core segments
-            for (core, pattern) in self.decoded_core_segment {
-                let mut pattern = pattern.chars();
-                match core {
-                    ClockNumber::One => {
-                        // Decode the segment
-                        let bit_index = bit_index_turned_on!(ClockNumber::One);
-                        for i in bit_index {
-                            self.decoded_segments[i as usize] = pattern.next().unwrap();
-                        }
-                    }
-                    ClockNumber::Seven => {
-                        // Decode segment7
-                        let bit_index_1 = bit_index_turned_on!(ClockNumber::One);
-                        let bit_index_7 = bit_index_turned_on!(ClockNumber::Seven);
-
-                        // set7 \ set1 = x
-                        for e in pattern {
-                            if self.decoded_segments[bit_index_1[0] as usize] != e
-                                && self.decoded_segments[bit_index_1[1] as usize] != e
-                            {
-                                self.decoded_segments[bit_index_7[0] as usize] = e;
-                                break;
-                            }
-                        }
-                    }
-                    ClockNumber::Four => {
-                        // Decode the segment
-                        let b_idx4 = bit_index_turned_on!(ClockNumber::Four);
-                        let mut idx = 0; // (0, 2)
-
-                        for e in pattern {
-                            if self.decoded_segments[b_idx4[1] as usize] != e
-                                && self.decoded_segments[b_idx4[3] as usize] != e
-                            {
-                                self.decoded_segments[b_idx4[idx] as usize] = e;
-                                if idx == 2 {
-                                    break;
-                                } else {
-                                    idx = 2;
-                                }
-                            }
-                        }
-                    }
-                    ClockNumber::Eight => {
-                        // Group segments with count 5 {2, 3, 5}
-                        let mut bit_count_six_numbers = self
-                            .signal_patterns
-                            .iter()
-                            .filter(|encoded_string| encoded_string.len() == 5)
-                            .collect::<Vec<_>>();
-
-                        // Find encoded_number3
-                        let mut encoded3: Option<usize> = None;
-                        let bc7 = bit_index_turned_on!(ClockNumber::Seven);
-                        let bits = &self.decoded_segments;
-                        for (idx, encoded_digits) in bit_count_six_numbers.iter().enumerate() {
-                            let mut found = 0;
-                            for e in encoded_digits.chars() {
-                                if bits[bc7[1] as usize] == e || bits[bc7[2] as usize] == e {
-                                    found += 1;
-                                }
-                            }
-                            if found == 2 {
-                                encoded3 = Some(idx);
-                                break;
-                            }
-                        }
-
-                        let encoded3 = bit_count_six_numbers
-                            .swap_remove(encoded3.expect("Encoded number 3 not found"));
-                        self.decoded_digits[3]
-                            .segment_chars
-                            .extend(encoded3.chars());
-
-                        // Resolve segment 1 & 3
-                        let mut third_segment: Option<char> = None;
-                        let bc4 = bit_index_turned_on!(ClockNumber::Four);
-                        for e in encoded3.chars() {
-                            if e == bits[bc4[0] as usize] || e == bits[bc4[2] as usize] {
-                                third_segment = Some(e);
-                                break;
-                            }
-                        }
-
-                        // Swap the segments (1, 3)
-                        let third_segment = third_segment.expect("Third segment not found");
-                        if third_segment != bits[bc4[2] as usize] {
-                            self.decoded_segments[bc4[0] as usize] =
-                                self.decoded_segments[bc4[2] as usize];
-                            self.decoded_segments[bc4[2] as usize] = third_segment;
-                        }
-
-                        // Resolve segment 6
-                        for e in encoded3.chars() {
-                            if !self.decoded_segments.iter().any(|c| *c == e) {
-                                self.decoded_segments[6] = e;
-                                break;
-                            }
-                        }
-
-                        // Resolve segment 2 & 5
-                        let mut encoded5: Option<usize> = None;
-                        let seg1 = self.decoded_segments[1];
-                        for (idx, encoded_digits) in bit_count_six_numbers.iter().enumerate() {
-                            if encoded_digits.chars().any(|e| e == seg1) {
-                                encoded5 = Some(idx);
-                                break;
-                            }
-                        }
-
-                        let bits = &self.decoded_segments;
-                        let encoded5 = bit_count_six_numbers
-                            .swap_remove(encoded5.expect("Encoded number 3 not found"));
-                        self.decoded_digits[5]
-                            .segment_chars
-                            .extend(encoded5.chars());
-                        let bc1 = bit_index_turned_on!(ClockNumber::One);
-                        let mut seg5 = None;
-                        for e in encoded5.chars() {
-                            if e == bits[bc1[0] as usize] || e == bits[bc1[1] as usize] {
-                                seg5 = Some(e);
-                                break;
-                            }
-                        }
-
-                        let seg5 = seg5.expect("Segment 5 not found in digit 5");
-                        if seg5 != bits[bc1[1] as usize] {
-                            self.decoded_segments[bc1[0] as usize] =
-                                self.decoded_segments[bc1[1] as usize];
-                            self.decoded_segments[bc1[1] as usize] = seg5;
-                        }
-
-                        let digit2 = bit_count_six_numbers.pop().expect("Digit 2 not found");
-
-                        // Resolve segment 4
-                        let bits = &self.decoded_segments;
-                        for e in digit2.chars() {
-                            if !bits.iter().any(|c| *c == e) {
-                                self.decoded_segments[4] = e;
-                                break;
-                            }
-                        }
-                    }
-                    other => panic!("{:?} is not a Core segment", other),
-                }
+            // wiring[wire] = segment, where wire 0 is 'a'
+            let mut wiring: [u8; SEGMENT_COUNT] = core::array::from_fn(|segment| segment as u8);
+            if !Self::search_wiring(signal_patterns, &mut wiring, 0) {
+                // Name the first pattern that cannot be satisfied alongside those before it
+                let inconsistent = (1..=signal_patterns.len())
+                    .find(|&len| !Self::search_wiring(&signal_patterns[..len], &mut wiring, 0))
+                    .expect("The full pattern set has no wiring");
+                return Err(DecodeError::InconsistentPattern(
+                    signal_patterns[inconsistent - 1].clone(),
+                ));
             }
 
-            let signal_patterns = self
-                .signal_patterns
-                .iter()
-                .map(|e| e.chars().collect::<HashSet<_>>())
-                .collect::<Vec<_>>();
-
-            for decoded_digit in self.decoded_digits.iter_mut() {
-                if !decoded_digit.is_decoded() {
-                    decoded_digit
-                        .segment_chars
-                        .extend(match decoded_digit.number {
-                            ClockNumber::Zero => {
-                                let b_idx0 = bit_index_turned_on!(ClockNumber::Zero);
-                                let zero = b_idx0
-                                    .map(|idx| self.decoded_segments[idx as usize])
-                                    .into_iter()
-                                    .collect::<HashSet<_>>();
-
-                                self.signal_patterns[Self::find_decoded(&signal_patterns, &zero)]
-                                    .chars()
-                            }
-                            ClockNumber::Six => {
-                                let b_idx6 = bit_index_turned_on!(ClockNumber::Six);
-                                let six = b_idx6
-                                    .map(|idx| self.decoded_segments[idx as usize])
-                                    .into_iter()
-                                    .collect::<HashSet<_>>();
-
-                                self.signal_patterns[Self::find_decoded(&signal_patterns, &six)]
-                                    .chars()
-                            }
-                            ClockNumber::Seven => {
-                                let b_idx7 = bit_index_turned_on!(ClockNumber::Seven);
-                                let seven = b_idx7
-                                    .map(|idx| self.decoded_segments[idx as usize])
-                                    .into_iter()
-                                    .collect::<HashSet<_>>();
-
-                                self.signal_patterns[Self::find_decoded(&signal_patterns, &seven)]
-                                    .chars()
-                            }
-                            ClockNumber::Eight => {
-                                let b_idx8 = bit_index_turned_on!(ClockNumber::Eight);
-                                let eight = b_idx8
-                                    .map(|idx| self.decoded_segments[idx as usize])
-                                    .into_iter()
-                                    .collect::<HashSet<_>>();
-
-                                self.signal_patterns[Self::find_decoded(&signal_patterns, &eight)]
-                                    .chars()
-                            }
-                            ClockNumber::Two => {
-                                let b_idx2 = bit_index_turned_on!(ClockNumber::Two);
-                                let two = b_idx2
-                                    .map(|idx| self.decoded_segments[idx as usize])
-                                    .into_iter()
-                                    .collect::<HashSet<_>>();
-
-                                self.signal_patterns[Self::find_decoded(&signal_patterns, &two)]
-                                    .chars()
-                            }
-                            ClockNumber::Nine => {
-                                let b_idx9 = bit_index_turned_on!(ClockNumber::Nine);
-                                let nine = b_idx9
-                                    .map(|idx| self.decoded_segments[idx as usize])
-                                    .into_iter()
-                                    .collect::<HashSet<_>>();
-
-                                self.signal_patterns[Self::find_decoded(&signal_patterns, &nine)]
-                                    .chars()
-                            }
-                            _ => panic!(
-                                "Digit cannot be decoded here {:?} {:?}",
-                                decoded_digit.number, decoded_digit.segment_chars
-                            ),
-                        });
-                }
+            let mut decoded_segments = ['\0'; SEGMENT_COUNT];
+            for (wire, &segment) in wiring.iter().enumerate() {
+                decoded_segments[segment as usize] = (b'a' + wire as u8) as char;
             }
+
+            Ok(Self {
+                decoded_segments,
+                output_value,
+            })
         }
 
-        fn find_decoded(
-            signal_patterns: &[HashSet<char>],
-            digit_segment_set: &HashSet<char>,
-        ) -> usize {
-            for (idx, e) in signal_patterns.iter().enumerate() {
-                if e.eq(digit_segment_set) {
-                    return idx;
-                }
+        /// Returns the resolved wiring, where index `i` holds the signal wire driving segment `i`
+        /// as numbered on `ClockNumber`.
+        #[allow(dead_code)]
+        pub fn wiring(&self) -> [char; SEGMENT_COUNT] {
+            self.decoded_segments
+        }
+
+        /// Decodes each of the output values into its digit.
+        ///
+        /// # Errors
+        /// If an output does not light up a digit under the resolved wiring.
+        pub fn decode(&self) -> Result<[u16; 4], DecodeError> {
+            let mut decoded_output: [u16; 4] = [0; 4];
+
+            for (idx, output) in self.output_value.iter().enumerate() {
+                decoded_output[idx] = self.decode_output(output)?.int_repr() as u16;
             }
-            panic!("Digit not found for segment set {:?}", digit_segment_set);
+
+            Ok(decoded_output)
+        }
+
+        fn decode_output(&self, output: &str) -> Result<ClockNumber, DecodeError> {
+            let mask = output.chars().fold(0, |mask, wire| {
+                let segment = self
+                    .decoded_segments
+                    .iter()
+                    .position(|&decoded| decoded == wire)
+                    .expect("Wires are validated in SignalDecoder::new");
+                mask | Self::segment_bit(segment as u8)
+            });
+
+            ClockNumber::from_mask(mask)
+                .ok_or_else(|| DecodeError::UnknownOutput(output.to_string()))
         }
 
-        pub fn new(signal_patterns: &'ctx [String; 10]) -> SignalDecoder {
-            let decoded_core_segment = Self::encoded_core_segments(signal_patterns);
-            let decoded_digits = [
-                Digits {
-                    number: ClockNumber::Zero,
-                    segment_chars: HashSet::new(),
-                },
-                Digits {
-                    number: ClockNumber::One,
-                    segment_chars: HashSet::from_iter(decoded_core_segment[0].1.chars()),
-                },
-                Digits {
-                    number: ClockNumber::Two,
-                    segment_chars: HashSet::new(),
-                },
-                Digits {
-                    number: ClockNumber::Three,
-                    segment_chars: HashSet::new(),
-                },
-                Digits {
-                    number: ClockNumber::Four,
-                    segment_chars: HashSet::from_iter(decoded_core_segment[2].1.chars()),
-                },
-                Digits {
-                    number: ClockNumber::Five,
-                    segment_chars: HashSet::new(),
-                },
-                Digits {
-                    number: ClockNumber::Six,
-                    segment_chars: HashSet::new(),
-                },
-                Digits {
-                    number: ClockNumber::Seven,
-                    segment_chars: HashSet::from_iter(decoded_core_segment[1].1.chars()),
-                },
-                Digits {
-                    number: ClockNumber::Eight,
-                    segment_chars: HashSet::from_iter(decoded_core_segment[3].1.chars()),
-                },
-                Digits {
-                    number: ClockNumber::Nine,
-                    segment_chars: HashSet::new(),
-                },
-            ];
-            Self {
-                decoded_digits,
-                signal_patterns,
-                decoded_core_segment,
-                decoded_segments: ['\0'; 7],
+        fn validate_wires(pattern: &str) -> Result<(), DecodeError> {
+            let mut seen = 0u8;
+            for wire in pattern.bytes() {
+                if !(b'a'..=b'g').contains(&wire) || seen & (1 << (wire - b'a')) != 0 {
+                    return Err(DecodeError::InvalidWires(pattern.to_string()));
+                }
+                seen |= 1 << (wire - b'a');
             }
+            Ok(())
         }
 
-        /// Returns the core segments with their encoded string patterns.
-        /// With ordering as [One, Four, Seven, Eight]
-        fn encoded_core_segments(signal_patterns: &[String; 10]) -> [(ClockNumber, &str); 4] {
-            let mut core_segments = [(ClockNumber::One, ""); 4];
-
-            for segment in signal_patterns {
-                match segment.len() as u8 {
-                    len if len == ClockNumber::One.count_segments() => {
-                        core_segments[0] = (ClockNumber::One, segment);
-                    }
-                    len if len == ClockNumber::Seven.count_segments() => {
-                        core_segments[1] = (ClockNumber::Seven, segment);
-                    }
-                    len if len == ClockNumber::Four.count_segments() => {
-                        core_segments[2] = (ClockNumber::Four, segment);
-                    }
-                    len if len == ClockNumber::Eight.count_segments() => {
-                        core_segments[3] = (ClockNumber::Eight, segment);
-                    }
-                    _ => (/* Non-core segments */),
+        /// Permutes `wiring[fixed..]` in place until every pattern maps to a digit.
+        ///
+        /// # Returns
+        /// `true` if such a wiring was found, leaving it in `wiring`.
+        fn search_wiring(
+            patterns: &[String],
+            wiring: &mut [u8; SEGMENT_COUNT],
+            fixed: usize,
+        ) -> bool {
+            if fixed == SEGMENT_COUNT {
+                return patterns.iter().all(|pattern| {
+                    ClockNumber::from_mask(Self::encode(pattern, wiring)).is_some()
+                });
+            }
+
+            for i in fixed..SEGMENT_COUNT {
+                wiring.swap(fixed, i);
+                if Self::search_wiring(patterns, wiring, fixed + 1) {
+                    return true;
                 }
+                wiring.swap(fixed, i);
             }
 
-            core_segments
+            false
         }
+
+        /// Returns the segment bitmask lit by `pattern` under the given `wiring`.
+        fn encode(pattern: &str, wiring: &[u8; SEGMENT_COUNT]) -> u8 {
+            pattern.bytes().fold(0, |mask, wire| {
+                mask | Self::segment_bit(wiring[(wire - b'a') as usize])
+            })
+        }
+
+        /// Segment 0 is the most significant of the 7 bits used by `ClockNumber`.
+        #[inline(always)]
+        fn segment_bit(segment: u8) -> u8 {
+            1 << (SEGMENT_COUNT as u8 - 1 - segment)
+        }
+    }
+}
+
+#[cfg(test)]
+mod decoder_tests {
+    use super::*;
+
+    const SAMPLE: &str =
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    #[test]
+    fn test_decode_sample() {
+        let signal_context = SAMPLE.parse::<SignalContext>().unwrap();
+        assert_eq!(signal_context.decode(), Ok([5, 3, 5, 3]));
+        assert_eq!(part2(vec![signal_context]), 5353);
+    }
+
+    #[test]
+    fn test_wiring() {
+        let signal_context = SAMPLE.parse::<SignalContext>().unwrap();
+        let decoder = SignalDecoder::new(
+            &signal_context.unique_signal_patterns,
+            &signal_context.output_value,
+        )
+        .unwrap();
+
+        let wiring = decoder.wiring();
+        assert_eq!(wiring, ['d', 'e', 'a', 'f', 'g', 'b', 'c']);
+
+        // Lighting every resolved wire must show an 8
+        let eight: [String; 4] = core::array::from_fn(|_| wiring.iter().collect());
+        let decoder = SignalDecoder::new(&signal_context.unique_signal_patterns, &eight).unwrap();
+        assert_eq!(decoder.decode(), Ok([8; 4]));
+    }
+
+    #[test]
+    fn test_parse_counts() {
+        let nine_patterns = SAMPLE.replace(" ab |", " |");
+        assert_eq!(
+            nine_patterns.parse::<SignalContext>().err(),
+            Some("Expected 10 signal patterns, found 9".to_string())
+        );
+
+        let five_outputs = format!("{SAMPLE} ab");
+        assert_eq!(
+            five_outputs.parse::<SignalContext>().err(),
+            Some("Expected 4 output values, found 5".to_string())
+        );
+
+        assert!("acedgfb cdfbe".parse::<SignalContext>().is_err());
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // "ab" (the digit 1) replaced by "eg", which contradicts "dab" and "eafb"
+        let inconsistent = SAMPLE.replace(" ab |", " eg |");
+        let err = inconsistent.parse::<SignalContext>().unwrap().decode();
+        assert_eq!(err, Err(DecodeError::InconsistentPattern("eg".to_string())));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Signal pattern 'eg' cannot be wired consistently with the patterns before it"
+        );
+
+        let invalid_wires = SAMPLE.replace("cdbaf", "cdbax");
+        let err = invalid_wires.parse::<SignalContext>().unwrap().decode();
+        assert_eq!(err, Err(DecodeError::InvalidWires("cdbax".to_string())));
+
+        let unknown_output = SAMPLE.replace("cdbaf", "ce");
+        let err = unknown_output.parse::<SignalContext>().unwrap().decode();
+        assert_eq!(err, Err(DecodeError::UnknownOutput("ce".to_string())));
     }
 }
@@ -1,10 +1,9 @@
-use crate::utils::coordinate_system::direction::Direction;
 use crate::utils::coordinate_system::Coordinate;
 use crate::utils::day_setup;
 use crate::utils::grid::unsized_grid::UnsizedGrid;
 use crate::utils::grid::Grid;
-use day_setup::Utils;
-use std::collections::{HashSet, VecDeque};
+use day_setup::{AssertionError, Utils};
+use std::collections::HashSet;
 use std::fmt::Debug;
 
 /// Runs the Advent of Code puzzles for [Current Day](https://adventofcode.com/2021/day/9).
@@ -12,12 +11,14 @@ use std::fmt::Debug;
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part_single(part1, 1, 9, Some(486));
-    Utils::run_part_single(part2, 2, 9, Some(1059300));
+pub fn run() -> Result<(), AssertionError> {
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part_single(part1, 1, 9, Some(486))?;
+    Utils::run_part_single(part2, 2, 9, Some(1059300))?;
+
+    Ok(())
 }
 
 /// Part 1 of the puzzle, which finds the smallest points in the height map.
@@ -28,11 +29,11 @@ pub fn run() {
 /// # Returns
 /// The sum of the risk levels of all the smallest points.
 fn part1(height_map: HeightMap) -> u64 {
-    height_map.grid.foreach(|pos, e: &u8, sum: &mut u64| {
-        if height_map.is_lowest_point(pos) {
-            *sum += *e as u64 + 1
-        }
-    })
+    height_map
+        .low_points()
+        .into_iter()
+        .map(|pos| *height_map.get(&pos).unwrap() as u64 + 1)
+        .sum()
 }
 
 /// Part 2 of the puzzle, which finds the largest basins in the height map.
@@ -43,41 +44,14 @@ fn part1(height_map: HeightMap) -> u64 {
 /// # Returns
 /// The product of the sizes of the three largest basins.
 fn part2(height_map: HeightMap) -> u64 {
-    height_map
-        .grid
-        .foreach(|pos, _, acc: &mut [u64; 3]| {
-            if height_map.is_lowest_point(pos) {
-                let mut queue = VecDeque::new();
-                let mut has_visited = HashSet::new();
-                queue.push_back(pos);
-
-                while let Some(pos) = queue.pop_front() {
-                    if !has_visited.insert(pos) {
-                        continue;
-                    }
-                    has_visited.insert(pos);
-                    for dir in Direction::direction_list() {
-                        let position = pos + dir;
-                        if let Some(&new_height) = height_map.get(&position) {
-                            if new_height < HeightMap::HIGHEST_POINT {
-                                queue.push_back(position);
-                            }
-                        }
-                    }
-                }
+    let mut basin_sizes = height_map
+        .low_points()
+        .into_iter()
+        .map(|pos| height_map.basin_size(pos) as u64)
+        .collect::<Vec<_>>();
 
-                if let Some(min) = acc.iter_mut().min() {
-                    let new_val = has_visited.len() as u64;
-                    if *min < new_val {
-                        *min = new_val;
-                    }
-                } else {
-                    panic!("Could not find min value")
-                }
-            }
-        })
-        .iter()
-        .product::<u64>()
+    basin_sizes.sort_unstable_by(|a, b| b.cmp(a));
+    basin_sizes.iter().take(3).product()
 }
 
 /// Represents a height map for the puzzle.
@@ -101,6 +75,46 @@ impl HeightMap {
         self.grid.get(position)
     }
 
+    /// Finds every point that is lower than all of its neighbours.
+    ///
+    /// # Returns
+    /// The coordinates of the low points, in row-major order.
+    fn low_points(&self) -> Vec<Coordinate> {
+        self.grid
+            .foreach(|pos, _, low_points: &mut Vec<Coordinate>| {
+                if self.is_lowest_point(pos) {
+                    low_points.push(pos);
+                }
+            })
+    }
+
+    /// Finds the basin flowing down into each low point, bounded by points of `HIGHEST_POINT`.
+    ///
+    /// # Returns
+    /// The coordinates of each basin, in the same order as `low_points`.
+    #[allow(dead_code)]
+    fn basins(&self) -> Vec<HashSet<Coordinate>> {
+        self.low_points()
+            .into_iter()
+            .map(|pos| self.basin(pos))
+            .collect()
+    }
+
+    /// Finds every point reachable from `start` without crossing a point of `HIGHEST_POINT`.
+    ///
+    /// # Returns
+    /// The coordinates of the basin, empty if `start` is out of bounds or is itself a
+    /// `HIGHEST_POINT`.
+    fn basin(&self, start: Coordinate) -> HashSet<Coordinate> {
+        self.grid
+            .flood_fill(start, |&height| height < HeightMap::HIGHEST_POINT)
+    }
+
+    /// Returns the number of points in the basin containing `start`.
+    fn basin_size(&self, start: Coordinate) -> usize {
+        self.basin(start).len()
+    }
+
     /// Checks if a position is the lowest point in its neighborhood.
     ///
     /// # Arguments
@@ -110,8 +124,7 @@ impl HeightMap {
     /// `true` if the position is the lowest point, `false` otherwise.
     fn is_lowest_point(&self, position: Coordinate) -> bool {
         let curr_height = *self.get(&position).unwrap();
-        for dir in Direction::direction_list() {
-            let new_pos = position + dir;
+        for new_pos in position.neighbours() {
             if let Some(new_height) = self.get(&new_pos) {
                 if *new_height <= curr_height {
                     return false;
@@ -132,20 +145,65 @@ impl From<Vec<String>> for HeightMap {
     /// # Returns
     /// A `HeightMap` created from the input strings.
     fn from(value: Vec<String>) -> Self {
-        let grid = value
-            .iter()
-            .map(|line| {
-                line.trim()
-                    .chars()
-                    .map(|c| c.to_digit(10).unwrap() as u8)
-                    .collect::<Vec<u8>>()
-                    .into_boxed_slice()
-            })
-            .collect::<Vec<Box<[u8]>>>()
-            .into_boxed_slice();
-
         Self {
-            grid: UnsizedGrid::from_box(grid),
+            grid: UnsizedGrid::from_digit_lines(&value).expect("Invalid height map"),
         }
     }
 }
+
+#[cfg(test)]
+mod height_map_tests {
+    use super::*;
+
+    const SAMPLE: [&str; 5] = [
+        "2199943210",
+        "3987894921",
+        "9856789892",
+        "8767896789",
+        "9899965678",
+    ];
+
+    fn sample() -> HeightMap {
+        HeightMap::from(SAMPLE.map(String::from).to_vec())
+    }
+
+    #[test]
+    fn test_low_points() {
+        let height_map = sample();
+        assert_eq!(
+            height_map.low_points(),
+            [
+                Coordinate::new(0, 1),
+                Coordinate::new(0, 9),
+                Coordinate::new(2, 2),
+                Coordinate::new(4, 6),
+            ]
+        );
+        assert_eq!(part1(height_map), 15);
+    }
+
+    #[test]
+    fn test_basins() {
+        let height_map = sample();
+        let mut sizes = height_map
+            .basins()
+            .iter()
+            .map(HashSet::len)
+            .collect::<Vec<_>>();
+        sizes.sort_unstable();
+
+        assert_eq!(sizes, [3, 9, 9, 14]);
+        assert_eq!(part2(height_map), 1134);
+    }
+
+    #[test]
+    fn test_basin_size_bounded_by_nines() {
+        let height_map = HeightMap::from(["99999", "91219", "99199"].map(String::from).to_vec());
+
+        // The basin runs along the middle row and down to the bottom edge
+        assert_eq!(height_map.basin_size(Coordinate::new(1, 1)), 4);
+        assert_eq!(height_map.basin_size(Coordinate::new(2, 2)), 4);
+        assert_eq!(height_map.basin_size(Coordinate::new(0, 0)), 0);
+        assert_eq!(height_map.basin_size(Coordinate::new(3, 2)), 0);
+    }
+}
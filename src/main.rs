@@ -21,9 +21,11 @@ mod day8;
 mod day9;
 mod utils;
 
+use utils::day_setup::DayRunner;
+
 fn main() {
     // utils::day_setup::Utils::new_day(21);
-    [
+    let days: [DayRunner; 21] = [
         day1::run,
         day2::run,
         day3::run,
@@ -39,14 +41,17 @@ fn main() {
         day16::run,
         day17::run,
         day18::run,
-        day12::run, // Incomplete
+        day12::run,
         day13::run,
         day15::run,
-        day19::run, // Incomplete
-        day20::run, // Incomplete
-        day21::run, // Incomplete
-    ]
-    // .iter().for_each(|day| { day(); println!() });
-    .last()
-    .unwrap()();
+        day19::run,
+        day20::run,
+        day21::run,
+    ];
+
+    let summary = utils::day_setup::Utils::run_all(&days);
+
+    if !summary.failures.is_empty() {
+        std::process::exit(1);
+    }
 }
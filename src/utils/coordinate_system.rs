@@ -1,8 +1,9 @@
 use std::fmt;
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 use std::str::FromStr;
 
 #[derive(Default, Clone, Copy, Eq, Ord, PartialEq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinate {
     pub i: i32,
     pub j: i32,
@@ -18,9 +19,73 @@ impl Coordinate {
         self.i.abs() + self.j.abs()
     }
 
+    /// Returns the Chebyshev distance between `self` and `other`.
+    ///
+    /// This is the minimal number of king-moves (any of the eight `FullDirection`s)
+    /// needed to travel between the two cells.
+    #[allow(dead_code)]
+    pub const fn chebyshev_distance_to(&self, other: &Coordinate) -> i32 {
+        let di = (self.i - other.i).abs();
+        let dj = (self.j - other.j).abs();
+        if di > dj {
+            di
+        } else {
+            dj
+        }
+    }
+
     pub const fn transpose(&self) -> Self {
         Self::new(self.j, self.i)
     }
+
+    /// Rotates the coordinate 90 degrees clockwise around the origin, mapping `(i, j)` to `(j, -i)`.
+    #[allow(dead_code)]
+    pub const fn rotate_cw(self) -> Self {
+        Self::new(self.j, -self.i)
+    }
+
+    /// Rotates the coordinate 90 degrees counter-clockwise around the origin, mapping `(i, j)` to `(-j, i)`.
+    #[allow(dead_code)]
+    pub const fn rotate_ccw(self) -> Self {
+        Self::new(-self.j, self.i)
+    }
+
+    /// Rotates the coordinate clockwise `times` quarter turns around the origin.
+    ///
+    /// Only `times % 4` rotations are applied, so large values cost the same as small ones.
+    #[allow(dead_code)]
+    pub const fn rotate_cw_n(self, times: u32) -> Self {
+        match times % 4 {
+            0 => self,
+            1 => self.rotate_cw(),
+            2 => Self::new(-self.i, -self.j),
+            _ => self.rotate_ccw(),
+        }
+    }
+
+    /// Returns an iterator over the four orthogonal neighbours of the coordinate,
+    /// in the order given by `Direction::direction_list()`.
+    pub fn neighbours(self) -> impl Iterator<Item = Coordinate> {
+        direction::Direction::direction_list()
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// Returns an iterator over the eight surrounding neighbours of the coordinate,
+    /// in the order given by `FullDirection::full_direction_list()`.
+    pub fn neighbours_8(self) -> impl Iterator<Item = Coordinate> {
+        direction::FullDirection::full_direction_list()
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// Scales both axes of the coordinate by `factor`.
+    ///
+    /// Scaling by `0` yields the origin and a negative factor flips the direction.
+    #[allow(dead_code)]
+    pub const fn scale(self, factor: i32) -> Self {
+        Self::new(self.i * factor, self.j * factor)
+    }
 }
 
 // Implementing the AddAssign trait for += operator
@@ -43,6 +108,33 @@ impl Add for Coordinate {
     }
 }
 
+// Implementing the SubAssign trait for -= operator
+impl SubAssign for Coordinate {
+    fn sub_assign(&mut self, other: Self) {
+        self.i -= other.i;
+        self.j -= other.j;
+    }
+}
+
+// Implementing the Sub trait for - operator with Coordinate
+// The result is the delta between both points, e.g. `(a - b).manhattan_distance()`
+impl Sub for Coordinate {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.i - other.i, self.j - other.j)
+    }
+}
+
+// Implementing the Mul trait for * operator with a scalar
+impl Mul<i32> for Coordinate {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
 // Implementing the Add trait for + operator with Direction
 impl Add<direction::Direction> for Coordinate {
     type Output = Self;
@@ -69,6 +161,23 @@ impl Add<direction::FullDirection> for Coordinate {
     }
 }
 
+/// Converts an `(i, j)` pair into a `Coordinate`.
+///
+/// The first element is the row (`i`) and the second is the column (`j`),
+/// matching the argument order of `Coordinate::new`.
+impl From<(i32, i32)> for Coordinate {
+    fn from((i, j): (i32, i32)) -> Self {
+        Self::new(i, j)
+    }
+}
+
+/// Converts a `Coordinate` into an `(i, j)` pair, i.e. `(row, column)`.
+impl From<Coordinate> for (i32, i32) {
+    fn from(coordinate: Coordinate) -> Self {
+        (coordinate.i, coordinate.j)
+    }
+}
+
 impl fmt::Debug for Coordinate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Coordinate({}, {})", self.i, self.j)
@@ -82,6 +191,7 @@ impl fmt::Debug for Coordinate {
 ///
 /// # Arguments
 /// * `line` - A string slice that holds the string representation of the coordinate in the format "x,y".
+///   Whitespace surrounding either number is ignored, so `" 4, 12"` is accepted.
 ///
 /// # Returns
 /// * `Result<Self, Self::Err>` - Returns `Ok(Self)` if parsing is successful, otherwise returns an `Err` with a descriptive error message.
@@ -98,10 +208,10 @@ impl FromStr for Coordinate {
         match line.split_once(',') {
             None => Err(format!("Invalid coordinate {}. Format is 'x,y'", line)),
             Some((i, j)) => {
-                let x = i.parse().map_err(|err: std::num::ParseIntError| {
+                let x = i.trim().parse().map_err(|err: std::num::ParseIntError| {
                     format!("Cannot parse i axis: {}", err)
                 })?;
-                let y = j.parse().map_err(|err: std::num::ParseIntError| {
+                let y = j.trim().parse().map_err(|err: std::num::ParseIntError| {
                     format!("Cannot parse j axis: {}", err)
                 })?;
                 Ok(Self::new(x, y))
@@ -110,6 +220,102 @@ impl FromStr for Coordinate {
     }
 }
 
+/// A point in 3D space, mirroring the 2D `Coordinate`.
+#[derive(Default, Clone, Copy, Eq, Ord, PartialEq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinate3D {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Coordinate3D {
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the Manhattan distance between `self` and `other`.
+    pub const fn manhattan_distance_to(&self, other: &Coordinate3D) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Returns the coordinate under each of the 24 axis-aligned rotations around the origin.
+    ///
+    /// The rotations are grouped by the direction the original x-axis ends up facing
+    /// (`+x`, `-x`, `+y`, `-y`, `+z`, `-z`), each followed by its four quarter turns
+    /// around that axis. The first entry is always the identity.
+    pub const fn rotations(self) -> [Coordinate3D; 24] {
+        let Self { x, y, z } = self;
+        [
+            Self::new(x, y, z),
+            Self::new(x, -z, y),
+            Self::new(x, -y, -z),
+            Self::new(x, z, -y),
+            Self::new(-x, -y, z),
+            Self::new(-x, z, y),
+            Self::new(-x, y, -z),
+            Self::new(-x, -z, -y),
+            Self::new(y, -x, z),
+            Self::new(y, z, x),
+            Self::new(y, x, -z),
+            Self::new(y, -z, -x),
+            Self::new(-y, x, z),
+            Self::new(-y, -z, x),
+            Self::new(-y, -x, -z),
+            Self::new(-y, z, -x),
+            Self::new(z, y, -x),
+            Self::new(z, x, y),
+            Self::new(z, -y, x),
+            Self::new(z, -x, -y),
+            Self::new(-z, y, x),
+            Self::new(-z, -x, y),
+            Self::new(-z, -y, -x),
+            Self::new(-z, x, -y),
+        ]
+    }
+}
+
+// Implementing the Add trait for + operator with Coordinate3D
+impl Add for Coordinate3D {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+// Implementing the Sub trait for - operator with Coordinate3D
+impl Sub for Coordinate3D {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl fmt::Debug for Coordinate3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Coordinate3D({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Parses a `Coordinate3D` from the format "x,y,z".
+impl FromStr for Coordinate3D {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut axes = line.split(',').map(|axis| {
+            axis.trim()
+                .parse::<i32>()
+                .map_err(|err| format!("Cannot parse axis of {}: {}", line, err))
+        });
+        match (axes.next(), axes.next(), axes.next(), axes.next()) {
+            (Some(x), Some(y), Some(z), None) => Ok(Self::new(x?, y?, z?)),
+            _ => Err(format!("Invalid coordinate {}. Format is 'x,y,z'", line)),
+        }
+    }
+}
+
 pub mod direction {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub enum Direction {
@@ -146,6 +352,54 @@ pub mod direction {
         pub const fn direction_list() -> [Direction; 4] {
             [Self::North, Self::East, Self::South, Self::West]
         }
+
+        /// Promotes the cardinal direction into the matching `FullDirection`.
+        #[allow(dead_code)]
+        pub const fn to_full(self) -> FullDirection {
+            match self {
+                Self::North => FullDirection::North,
+                Self::East => FullDirection::East,
+                Self::South => FullDirection::South,
+                Self::West => FullDirection::West,
+                Self::Current => FullDirection::Current,
+            }
+        }
+
+        /// Returns the direction pointing the opposite way. `Current` stays `Current`.
+        #[allow(dead_code)]
+        pub const fn opposite(self) -> Self {
+            match self {
+                Self::North => Self::South,
+                Self::East => Self::West,
+                Self::South => Self::North,
+                Self::West => Self::East,
+                Self::Current => Self::Current,
+            }
+        }
+
+        /// Returns the direction after a 90 degree counter-clockwise turn. `Current` stays `Current`.
+        #[allow(dead_code)]
+        pub const fn turn_left(self) -> Self {
+            match self {
+                Self::North => Self::West,
+                Self::East => Self::North,
+                Self::South => Self::East,
+                Self::West => Self::South,
+                Self::Current => Self::Current,
+            }
+        }
+
+        /// Returns the direction after a 90 degree clockwise turn. `Current` stays `Current`.
+        #[allow(dead_code)]
+        pub const fn turn_right(self) -> Self {
+            match self {
+                Self::North => Self::East,
+                Self::East => Self::South,
+                Self::South => Self::West,
+                Self::West => Self::North,
+                Self::Current => Self::Current,
+            }
+        }
     }
 
     impl TryFrom<char> for Direction {
@@ -225,6 +479,22 @@ pub mod direction {
                 Self::NorthWest,
             ]
         }
+
+        /// Returns the direction pointing the opposite way. `Current` stays `Current`.
+        #[allow(dead_code)]
+        pub const fn opposite(self) -> Self {
+            match self {
+                Self::North => Self::South,
+                Self::NorthEast => Self::SouthWest,
+                Self::East => Self::West,
+                Self::SouthEast => Self::NorthWest,
+                Self::South => Self::North,
+                Self::SouthWest => Self::NorthEast,
+                Self::West => Self::East,
+                Self::NorthWest => Self::SouthEast,
+                Self::Current => Self::Current,
+            }
+        }
     }
 
     impl TryFrom<&str> for FullDirection {
@@ -245,3 +515,161 @@ pub mod direction {
         }
     }
 }
+
+#[cfg(test)]
+mod coordinate_tests {
+    use super::*;
+
+    #[test]
+    fn test_scale() {
+        let coordinate = Coordinate::new(2, -3);
+        assert_eq!(coordinate * -4, Coordinate::new(-8, 12));
+        assert_eq!(coordinate.scale(-4), Coordinate::new(-8, 12));
+        assert_eq!(coordinate.scale(0), Coordinate::default());
+    }
+
+    #[test]
+    fn test_chebyshev_distance() {
+        let origin = Coordinate::new(0, 0);
+        assert_eq!(origin.chebyshev_distance_to(&Coordinate::new(3, 1)), 3);
+        assert_eq!(Coordinate::new(3, 1).chebyshev_distance_to(&origin), 3);
+        assert_eq!(origin.chebyshev_distance_to(&Coordinate::new(-2, 5)), 5);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let coordinate = Coordinate::new(2, -7);
+        assert_eq!(coordinate.rotate_cw(), Coordinate::new(-7, -2));
+        assert_eq!(coordinate.rotate_ccw(), Coordinate::new(7, 2));
+        assert_eq!(coordinate.rotate_cw().rotate_ccw(), coordinate);
+        assert_eq!(
+            coordinate.rotate_cw().rotate_cw().rotate_cw().rotate_cw(),
+            coordinate
+        );
+
+        for times in 0..12 {
+            let looped = (0..times % 4).fold(coordinate, |acc, _| acc.rotate_cw());
+            assert_eq!(coordinate.rotate_cw_n(times), looped);
+        }
+        assert_eq!(coordinate.rotate_cw_n(u32::MAX), coordinate.rotate_ccw());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("6,10".parse::<Coordinate>(), Ok(Coordinate::new(6, 10)));
+        assert_eq!(" 4, 12".parse::<Coordinate>(), Ok(Coordinate::new(4, 12)));
+        assert!("4 12".parse::<Coordinate>().is_err());
+        assert!("4,x".parse::<Coordinate>().is_err());
+        assert!("4,12,3".parse::<Coordinate>().is_err());
+    }
+
+    #[test]
+    fn test_tuple_conversion() {
+        let coordinate = Coordinate::from((3, -1));
+        assert_eq!(coordinate.i, 3);
+        assert_eq!(coordinate.j, -1);
+        assert_eq!(<(i32, i32)>::from(coordinate), (3, -1));
+    }
+
+    #[test]
+    fn test_coordinate_3d() {
+        let a = Coordinate3D::new(1105, -1205, 1229);
+        let b = Coordinate3D::new(-92, -2380, -20);
+        assert_eq!(a - b + b, a);
+        assert_eq!(a.manhattan_distance_to(&b), 3621);
+        assert_eq!("1105,-1205,1229".parse::<Coordinate3D>(), Ok(a));
+        assert!("1,2".parse::<Coordinate3D>().is_err());
+
+        let rotations = Coordinate3D::new(1, 2, 3).rotations();
+        assert_eq!(rotations[0], Coordinate3D::new(1, 2, 3));
+        assert_eq!(
+            rotations
+                .iter()
+                .collect::<std::collections::HashSet<_>>()
+                .len(),
+            24
+        );
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        use direction::Direction;
+
+        assert_eq!(Direction::North.opposite(), Direction::South);
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(
+            Direction::North
+                .turn_right()
+                .turn_right()
+                .turn_right()
+                .turn_right(),
+            Direction::North
+        );
+        for direction in Direction::direction_list() {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        }
+    }
+
+    #[test]
+    fn test_direction_to_full() {
+        use direction::{Direction, FullDirection};
+
+        assert_eq!(Direction::North.to_full(), FullDirection::North);
+        assert_eq!(Direction::East.to_full(), FullDirection::East);
+        assert_eq!(Direction::South.to_full(), FullDirection::South);
+        assert_eq!(Direction::West.to_full(), FullDirection::West);
+        for direction in Direction::direction_list() {
+            assert_eq!(direction.offset(), direction.to_full().offset());
+            assert_eq!(
+                direction.opposite().to_full(),
+                direction.to_full().opposite()
+            );
+        }
+        for direction in FullDirection::full_direction_list() {
+            let (di, dj) = direction.offset();
+            assert_eq!(direction.opposite().offset(), (-di, -dj));
+        }
+    }
+
+    #[test]
+    fn test_neighbours() {
+        use direction::{Direction, FullDirection};
+
+        let coordinate = Coordinate::new(4, 2);
+        assert!(coordinate
+            .neighbours()
+            .eq(Direction::direction_list().map(|d| coordinate + d)));
+        assert!(coordinate
+            .neighbours_8()
+            .eq(FullDirection::full_direction_list().map(|d| coordinate + d)));
+        assert_eq!(
+            coordinate.neighbours().collect::<Vec<_>>(),
+            vec![
+                Coordinate::new(3, 2),
+                Coordinate::new(4, 3),
+                Coordinate::new(5, 2),
+                Coordinate::new(4, 1),
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let coordinates = vec![Coordinate::new(0, 0), Coordinate::new(-3, 7)];
+
+        let json = serde_json::to_string(&coordinates).unwrap();
+        assert_eq!(json, r#"[{"i":0,"j":0},{"i":-3,"j":7}]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<Coordinate>>(&json).unwrap(),
+            coordinates
+        );
+
+        let beacon = Coordinate3D::new(1, -2, 3);
+        let json = serde_json::to_string(&beacon).unwrap();
+        assert_eq!(json, r#"{"x":1,"y":-2,"z":3}"#);
+        assert_eq!(serde_json::from_str::<Coordinate3D>(&json).unwrap(), beacon);
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Tallies how many times each item occurs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<T: Hash + Eq> {
+    counts: HashMap<T, u64>,
+}
+
+impl<T: Hash + Eq> Counter<T> {
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Adds `count` occurrences of `item`.
+    pub fn add(&mut self, item: T, count: u64) {
+        *self.counts.entry(item).or_insert(0) += count;
+    }
+
+    /// Returns the number of occurrences of `item`, which is 0 if it was never added.
+    #[allow(dead_code)]
+    pub fn get(&self, item: &T) -> u64 {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Returns every item sharing the highest count, in no particular order.
+    ///
+    /// The result is empty if nothing has been counted yet.
+    #[allow(dead_code)]
+    pub fn most_common(&self) -> Vec<&T> {
+        self.items_with(self.counts.values().max())
+    }
+
+    /// Returns every item sharing the lowest count, in no particular order.
+    ///
+    /// The result is empty if nothing has been counted yet.
+    #[allow(dead_code)]
+    pub fn least_common(&self) -> Vec<&T> {
+        self.items_with(self.counts.values().min())
+    }
+
+    /// Returns the lowest and highest count, or `None` if nothing has been counted yet.
+    pub fn min_max(&self) -> Option<(u64, u64)> {
+        let min = self.counts.values().min()?;
+        let max = self.counts.values().max()?;
+
+        Some((*min, *max))
+    }
+
+    fn items_with(&self, count: Option<&u64>) -> Vec<&T> {
+        self.counts
+            .iter()
+            .filter(|(_, c)| Some(*c) == count)
+            .map(|(item, _)| item)
+            .collect()
+    }
+}
+
+impl<T: Hash + Eq> Default for Counter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counts one occurrence for every item of the iterator.
+impl<T: Hash + Eq> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        for item in iter {
+            counter.add(item, 1);
+        }
+
+        counter
+    }
+}
+
+#[cfg(test)]
+mod counter_tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_add_and_get() {
+        let mut counter = "NNCB".chars().collect::<Counter<_>>();
+        assert_eq!(counter.get(&'N'), 2);
+        assert_eq!(counter.get(&'C'), 1);
+        assert_eq!(counter.get(&'H'), 0);
+
+        counter.add('H', 5);
+        counter.add('N', 1);
+        assert_eq!(counter.get(&'H'), 5);
+        assert_eq!(counter.get(&'N'), 3);
+        assert_eq!(counter.min_max(), Some((1, 5)));
+    }
+
+    #[test]
+    fn test_most_and_least_common() {
+        let counter = ["a", "b", "b", "c", "c", "d"]
+            .into_iter()
+            .collect::<Counter<_>>();
+
+        assert_eq!(
+            counter.most_common().into_iter().collect::<HashSet<_>>(),
+            HashSet::from([&"b", &"c"])
+        );
+        assert_eq!(
+            counter.least_common().into_iter().collect::<HashSet<_>>(),
+            HashSet::from([&"a", &"d"])
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let counter = Counter::<char>::default();
+
+        assert!(counter.most_common().is_empty());
+        assert!(counter.least_common().is_empty());
+        assert_eq!(counter.min_max(), None);
+    }
+}
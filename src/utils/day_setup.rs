@@ -1,13 +1,141 @@
 use std::env;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 /// Utility struct containing various helper functions.
 pub struct Utils;
 
+/// The error returned when the result of a part does not match its expected value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertionError {
+    /// The `Debug` representation of the expected result.
+    pub expected: String,
+    /// The `Debug` representation of the result that was actually found.
+    pub found: String,
+}
+
+impl std::fmt::Display for AssertionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"
+Assertion Failed
+----------------
+Expected: {}
+Found: {}
+            "#,
+            self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for AssertionError {}
+
+/// The `run` function of a day, as called by `main`.
+pub type DayRunner = fn() -> Result<(), AssertionError>;
+
+/// The outcome of [`Utils::run_all`].
+#[derive(Debug)]
+pub struct RunSummary {
+    /// The number of days that were run.
+    #[allow(dead_code)]
+    pub ran: usize,
+    /// The position (in the list given to `run_all`) and error of every day that failed.
+    pub failures: Vec<(usize, AssertionError)>,
+}
+
+/// Timing statistics collected by [`Utils::bench_part`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    /// The number of times the part was run.
+    pub runs: usize,
+    /// The fastest run.
+    pub min: Duration,
+    /// The median run.
+    pub median: Duration,
+    /// The mean of all runs.
+    pub mean: Duration,
+}
+
+/// A part that has been run by [`Utils::run_part_with`], handed to its [`PartReport`].
+struct PartRun<'a, R> {
+    part_num: i32,
+    day_num: u8,
+    result: &'a R,
+    expected: Option<&'a R>,
+    parsing_time: Duration,
+    elapsed_time: Duration,
+}
+
+/// How [`Utils::run_part_with`] reports the progress of a part.
+trait PartReport<R> {
+    /// Called before the input is parsed.
+    fn start(&mut self, _part_num: i32, _day_num: u8) {}
+    /// Called once the input has been parsed, before the function runs.
+    fn parsed(&mut self, _parsing_time: Duration) {}
+    /// Called once the function has returned, whether or not its result matched.
+    fn finished(&mut self, run: &PartRun<R>);
+}
+
+/// Prints the decorative banner, timings and result of a part to stdout.
+struct BannerReport;
+
+impl<R: Debug + PartialEq> PartReport<R> for BannerReport {
+    fn start(&mut self, part_num: i32, day_num: u8) {
+        println!(
+            "//------------[Day {} Part {}]------------\\\\",
+            day_num, part_num
+        );
+    }
+
+    fn parsed(&mut self, parsing_time: Duration) {
+        println!(
+            "Time taken to parse: {:?}",
+            Utils::log_elapsed_time(parsing_time)
+        );
+    }
+
+    fn finished(&mut self, run: &PartRun<R>) {
+        // The assumption is that no advent of code answer is to ever be zero cuz that'll be boring
+        match run.expected {
+            None => println!("INCOMPLETE | Temp Result: {:?}", run.result),
+            // The mismatch is reported by the caller through the returned `AssertionError`
+            Some(expected) if expected != run.result => return,
+            Some(_) => println!(
+                "Result: {:?}\t| Time Taken: {}",
+                run.result,
+                Utils::log_elapsed_time(run.elapsed_time)
+            ),
+        }
+
+        println!(
+            "Total time taken: {:?}",
+            Utils::log_elapsed_time(run.parsing_time + run.elapsed_time)
+        );
+    }
+}
+
+/// Writes a part as a single JSON line, see [`Utils::run_part_json`].
+struct JsonReport<'a, W>(&'a mut W);
+
+impl<R: Display + PartialEq, W: Write> PartReport<R> for JsonReport<'_, W> {
+    fn finished(&mut self, run: &PartRun<R>) {
+        writeln!(
+            self.0,
+            r#"{{"day":{},"part":{},"result":"{}","micros":{},"passed":{}}}"#,
+            run.day_num,
+            run.part_num,
+            Utils::escape_json(&run.result.to_string()),
+            run.elapsed_time.as_micros(),
+            run.expected == Some(run.result)
+        )
+        .expect("Failed to write the JSON result");
+    }
+}
+
 impl Utils {
     /// Executes a function with a list of data and measures its execution time.
     ///
@@ -24,30 +152,66 @@ impl Utils {
     /// * `F` - The function type that takes a vector of `T` and returns a result of type `R`.
     /// * `R` - The type of the result returned by the function. Must implement the `Debug` and `PartialEq` traits.
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// This function will panic if the expected result does not match the actual result.
+    /// The result of the function, or an `AssertionError` if it does not match `expected`.
     pub fn run_part<T, F, R>(
         day_func_part_to_run: F,
         part_num: i32,
         day_num: u8,
         expected: Option<R>,
-    ) where
+    ) -> Result<R, AssertionError>
+    where
         F: FnOnce(Vec<T>) -> R,
         R: Debug + PartialEq,
         T: std::str::FromStr,
         T::Err: Debug,
     {
-        println!(
-            "//------------[Day {} Part {}]------------\\\\",
-            day_num, part_num
-        );
-
-        let read_file = Self::read_file::<T>(day_num);
-
-        let (elapsed_time, result) = Self::time_it(move || day_func_part_to_run(read_file));
+        Self::run_part_with(
+            &mut BannerReport,
+            || Self::read_file::<T>(day_num),
+            day_func_part_to_run,
+            part_num,
+            day_num,
+            expected,
+        )
+    }
 
-        Self::log_results(expected, result, elapsed_time);
+    /// Executes a function with the whole, unsplit input file and measures its execution time.
+    ///
+    /// # Arguments
+    ///
+    /// * `day_func_part_to_run` - The function to be executed.
+    /// * `part_num` - The part number of the puzzle.
+    /// * `day_num` - The day number of the puzzle.
+    /// * `expected` - The expected result for assertion.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The function type that takes the file contents as a `String` and returns a result of type `R`.
+    /// * `R` - The type of the result returned by the function. Must implement the `Debug` and `PartialEq` traits.
+    ///
+    /// # Returns
+    ///
+    /// The result of the function, or an `AssertionError` if it does not match `expected`.
+    pub fn run_part_raw<F, R>(
+        day_func_part_to_run: F,
+        part_num: i32,
+        day_num: u8,
+        expected: Option<R>,
+    ) -> Result<R, AssertionError>
+    where
+        F: FnOnce(String) -> R,
+        R: Debug + PartialEq,
+    {
+        Self::run_part_with(
+            &mut BannerReport,
+            || Self::read_file_raw(day_num),
+            day_func_part_to_run,
+            part_num,
+            day_num,
+            expected,
+        )
     }
 
     const AOC_YEAR: u16 = 21; // 2021
@@ -67,94 +231,301 @@ impl Utils {
     /// * `F` - The function type that takes an input of type `T` and returns a result of type `R`.
     /// * `R` - The type of the result returned by the function. Must implement the `Debug` and `PartialEq` traits.
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// This function will panic if the expected result does not match the actual result.
+    /// The result of the function, or an `AssertionError` if it does not match `expected`.
     pub fn run_part_single<T, F, R>(
         day_func_part_to_run: F,
         part_num: i32,
         day_num: u8,
         expected: Option<R>,
-    ) where
+    ) -> Result<R, AssertionError>
+    where
         F: FnOnce(T) -> R,
         T: From<Vec<String>>,
         R: Debug + PartialEq,
     {
-        println!(
-            "//------------[Day {} Part {}]------------\\\\",
-            day_num, part_num
-        );
+        Self::run_part_with(
+            &mut BannerReport,
+            || T::from(Self::read_file::<String>(day_num)),
+            day_func_part_to_run,
+            part_num,
+            day_num,
+            expected,
+        )
+    }
 
-        let read_file = Self::read_file::<String>(day_num);
-        let (parsing_time, final_type) = Self::time_it(move || T::from(read_file));
-        println!(
-            "Time taken to parse: {:?}",
-            Self::log_elapsed_time(parsing_time)
-        );
+    /// Shared implementation of the `run_part*` functions: parses the input, runs the function on it,
+    /// and checks the result, timing both steps separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `report` - Where the progress and result of the part are reported.
+    /// * `parse_input` - Reads and parses the input of the day.
+    /// * `day_func_part_to_run` - The function to be executed.
+    /// * `part_num` - The part number of the puzzle.
+    /// * `day_num` - The day number of the puzzle.
+    /// * `expected` - The expected result for assertion.
+    ///
+    /// # Returns
+    ///
+    /// The result of the function, or an `AssertionError` if it does not match `expected`.
+    fn run_part_with<I, P, F, R>(
+        report: &mut impl PartReport<R>,
+        parse_input: P,
+        day_func_part_to_run: F,
+        part_num: i32,
+        day_num: u8,
+        expected: Option<R>,
+    ) -> Result<R, AssertionError>
+    where
+        P: FnOnce() -> I,
+        F: FnOnce(I) -> R,
+        R: Debug + PartialEq,
+    {
+        report.start(part_num, day_num);
 
-        let (elapsed_time, result) = Self::time_it(move || day_func_part_to_run(final_type));
+        let (parsing_time, input) = Self::time_it(parse_input);
+        report.parsed(parsing_time);
 
-        Self::log_results(expected, result, elapsed_time);
+        let (elapsed_time, result) = Self::time_it(move || day_func_part_to_run(input));
+        report.finished(&PartRun {
+            part_num,
+            day_num,
+            result: &result,
+            expected: expected.as_ref(),
+            parsing_time,
+            elapsed_time,
+        });
 
-        println!(
-            "Total time taken: {:?}",
-            Self::log_elapsed_time(parsing_time + elapsed_time)
-        );
+        match expected {
+            Some(expected) if result != expected => Err(AssertionError {
+                expected: format!("{:?}", expected),
+                found: format!("{:?}", result),
+            }),
+            _ => Ok(result),
+        }
     }
 
-    fn time_it<R, F>(func: F) -> (Duration, R)
+    /// Like [`Utils::run_part`], but prints a single JSON line instead of the decorative banner so the
+    /// result can be piped into other tools:
+    ///
+    /// `{"day":1,"part":2,"result":"1575","micros":62,"passed":true}`
+    ///
+    /// `result` holds the `Display` form of the result, so string answers are not quoted twice,
+    /// `micros` the time taken by the function (excluding parsing) and `passed` whether the result
+    /// matched `expected`, which is `false` when there is no expected value yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `day_func_part_to_run` - The function to be executed.
+    /// * `part_num` - The part number of the puzzle.
+    /// * `day_num` - The day number of the puzzle.
+    /// * `expected` - The expected result for assertion.
+    ///
+    /// # Returns
+    ///
+    /// The result of the function, or an `AssertionError` if it does not match `expected`. The JSON
+    /// line is printed in both cases.
+    #[allow(dead_code)]
+    pub fn run_part_json<T, F, R>(
+        day_func_part_to_run: F,
+        part_num: i32,
+        day_num: u8,
+        expected: Option<R>,
+    ) -> Result<R, AssertionError>
     where
-        F: FnOnce() -> R,
+        F: FnOnce(Vec<T>) -> R,
+        R: Debug + Display + PartialEq,
+        T: std::str::FromStr,
+        T::Err: Debug,
     {
-        let start_time = Instant::now();
-        let result = func();
-        let elapsed_time = start_time.elapsed();
-        (elapsed_time, result)
+        Self::run_part_json_to(
+            &mut std::io::stdout().lock(),
+            || Self::read_file::<T>(day_num),
+            day_func_part_to_run,
+            part_num,
+            day_num,
+            expected,
+        )
+    }
+
+    /// Implementation of [`Utils::run_part_json`] reading the input with `parse_input` and writing
+    /// the JSON line to `out`.
+    fn run_part_json_to<W, I, P, F, R>(
+        out: &mut W,
+        parse_input: P,
+        day_func_part_to_run: F,
+        part_num: i32,
+        day_num: u8,
+        expected: Option<R>,
+    ) -> Result<R, AssertionError>
+    where
+        W: Write,
+        P: FnOnce() -> I,
+        F: FnOnce(I) -> R,
+        R: Debug + Display + PartialEq,
+    {
+        Self::run_part_with(
+            &mut JsonReport(out),
+            parse_input,
+            day_func_part_to_run,
+            part_num,
+            day_num,
+            expected,
+        )
     }
 
-    /// Logs the results of a function execution, including the expected result, actual result, and execution time.
+    /// Escapes `s` so it can be placed between the quotes of a JSON string.
+    fn escape_json(s: &str) -> String {
+        let mut escaped = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c {
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+                c => escaped.push(c),
+            }
+        }
+
+        escaped
+    }
+
+    /// Runs every given day in order, continuing past days whose results do not match.
     ///
     /// # Arguments
     ///
-    /// * `expected` - The expected result for assertion. If `None`, the result is considered incomplete.
-    /// * `result` - The actual result obtained from the function execution.
-    /// * `elapsed_time` - The duration of time taken to execute the function.
+    /// * `days` - The `run` functions of the days to run.
+    ///
+    /// # Returns
+    ///
+    /// A `RunSummary` of how many days ran and which of them failed.
+    pub fn run_all(days: &[DayRunner]) -> RunSummary {
+        let mut failures = vec![];
+        let (elapsed_time, _) = Self::time_it(|| {
+            for (i, day) in days.iter().enumerate() {
+                println!("==========[{}/{}]==========", i + 1, days.len());
+                if let Err(assertion_error) = day() {
+                    println!("{}", assertion_error);
+                    failures.push((i, assertion_error));
+                }
+                println!();
+            }
+        });
+
+        println!(
+            "Ran {} days ({} failed) in {}",
+            days.len(),
+            failures.len(),
+            Self::log_elapsed_time(elapsed_time)
+        );
+
+        RunSummary {
+            ran: days.len(),
+            failures,
+        }
+    }
+
+    /// Runs a function many times on the same input and reports min/median/mean timings.
+    ///
+    /// The input is parsed once and cloned before every run, so only the function itself is measured.
+    ///
+    /// # Arguments
+    ///
+    /// * `day_func_part_to_run` - The function to be benchmarked.
+    /// * `part_num` - The part number of the puzzle.
+    /// * `day_num` - The day number of the puzzle.
+    /// * `runs` - How many times to run the function. Must be greater than zero.
     ///
     /// # Type Parameters
     ///
-    /// * `R` - The type of the result. Must implement the `Debug` and `PartialEq` traits.
+    /// * `T` - The type of the elements in the input vector. Must implement the `FromStr` and `Clone` traits.
+    /// * `F` - The function type that takes a vector of `T` and returns a result of type `R`.
+    /// * `R` - The type of the result returned by the function.
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// This function will panic if the actual result does not match the expected result.
-    fn log_results<R>(expected: Option<R>, result: R, elapsed_time: Duration)
+    /// The collected `BenchStats`, or an error if `runs` is zero.
+    #[allow(dead_code)]
+    pub fn bench_part<T, F, R>(
+        day_func_part_to_run: F,
+        part_num: i32,
+        day_num: u8,
+        runs: usize,
+    ) -> Result<BenchStats, String>
     where
-        R: Debug + PartialEq,
+        F: Fn(Vec<T>) -> R,
+        T: std::str::FromStr + Clone,
+        T::Err: Debug,
     {
-        // The assumption is that no advent of code answer is to ever be zero cuz that'll be boring
-        match expected {
-            None => println!("INCOMPLETE | Temp Result: {:?}", result),
-            Some(expected) => {
-                if result != expected {
-                    println!(
-                        r#"
-Assertion Failed
-----------------
-Expected: {:?}
-Found: {:?}
-            "#,
-                        expected, result
-                    );
-                    std::process::exit(1);
-                }
+        Self::bench_part_with(
+            || Self::read_file::<T>(day_num),
+            day_func_part_to_run,
+            part_num,
+            day_num,
+            runs,
+        )
+    }
 
-                println!(
-                    "Result: {:?}\t| Time Taken: {}",
-                    result,
-                    Self::log_elapsed_time(elapsed_time)
-                );
-            }
+    /// Implementation of [`Utils::bench_part`] reading the input with `parse_input`.
+    fn bench_part_with<I, P, F, R>(
+        parse_input: P,
+        day_func_part_to_run: F,
+        part_num: i32,
+        day_num: u8,
+        runs: usize,
+    ) -> Result<BenchStats, String>
+    where
+        I: Clone,
+        P: FnOnce() -> I,
+        F: Fn(I) -> R,
+    {
+        if runs == 0 {
+            return Err("Cannot benchmark a part with zero runs".to_string());
         }
+
+        println!(
+            "//------------[Day {} Part {} Bench x{}]------------\\\\",
+            day_num, part_num, runs
+        );
+
+        let input = parse_input();
+        let mut timings = (0..runs)
+            .map(|_| {
+                let input = input.clone();
+                let (elapsed_time, _) = Self::time_it(|| day_func_part_to_run(input));
+                elapsed_time
+            })
+            .collect::<Vec<_>>();
+        timings.sort();
+
+        let stats = BenchStats {
+            runs,
+            min: timings[0],
+            median: timings[runs / 2],
+            mean: timings.iter().sum::<Duration>() / runs as u32,
+        };
+        println!(
+            "Min: {}\t| Median: {}\t| Mean: {}",
+            Self::log_elapsed_time(stats.min),
+            Self::log_elapsed_time(stats.median),
+            Self::log_elapsed_time(stats.mean)
+        );
+
+        Ok(stats)
+    }
+
+    fn time_it<R, F>(func: F) -> (Duration, R)
+    where
+        F: FnOnce() -> R,
+    {
+        let start_time = Instant::now();
+        let result = func();
+        let elapsed_time = start_time.elapsed();
+        (elapsed_time, result)
     }
 
     fn log_elapsed_time(elapsed_time: Duration) -> String {
@@ -194,16 +565,19 @@ Found: {:?}
         T: std::str::FromStr,
         T::Err: Debug,
     {
-        let file_path = Self::get_file_path()
-            .join("inputs")
-            .join(if day_num == 0 {
-                "Example".to_string()
-            } else {
-                format!("day{}", day_num)
-            })
-            .with_extension("txt");
+        Self::read_lines(&Self::get_input_path(day_num))
+    }
 
-        let file = File::open(&file_path)
+    /// Reads the file at `file_path` and parses every line of it as a `T`.
+    ///
+    /// # Panics
+    ///  If the file cannot be opened or if parsing an element fails.
+    fn read_lines<T>(file_path: &Path) -> Vec<T>
+    where
+        T: std::str::FromStr,
+        T::Err: Debug,
+    {
+        let file = File::open(file_path)
             .unwrap_or_else(|_| panic!("Failed to open file at {}", file_path.display()));
         let reader = BufReader::new(file);
         reader
@@ -212,6 +586,189 @@ Found: {:?}
             .collect()
     }
 
+    /// Reads a file and returns its whole content as a single `String`, without any splitting.
+    ///
+    /// # Arguments
+    /// * `day_num` - The day number of the puzzle.
+    ///
+    /// # Returns
+    /// * `String` - The content of the file, including any trailing newline.
+    ///
+    /// # Panics
+    ///  If the file cannot be read.
+    pub fn read_file_raw(day_num: u8) -> String {
+        Self::read_raw(&Self::get_input_path(day_num))
+    }
+
+    /// Downloads the puzzle input of a day from adventofcode.com into `src/inputs/day{n}.txt`.
+    ///
+    /// To respect Advent of Code's etiquette, an input that is already on disk is never fetched or
+    /// overwritten again.
+    ///
+    /// # Arguments
+    /// * `day_num` - The day number of the puzzle.
+    /// * `session_cookie` - The value of the `session` cookie of a logged-in adventofcode.com session.
+    ///
+    /// # Errors
+    ///  `AlreadyExists` if the input file already exists, or any error of the request or the write.
+    #[cfg(feature = "net")]
+    #[allow(dead_code)]
+    pub fn download_input(day_num: u8, session_cookie: &str) -> std::io::Result<()> {
+        let url = format!(
+            "https://adventofcode.com/20{}/day/{}/input",
+            Self::AOC_YEAR,
+            day_num
+        );
+        Self::download_input_to(&url, session_cookie, &Self::get_input_path(day_num))
+    }
+
+    /// Downloads `url` into `file_path` unless the file already exists.
+    #[cfg(feature = "net")]
+    fn download_input_to(url: &str, session_cookie: &str, file_path: &Path) -> std::io::Result<()> {
+        if file_path.exists() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!("Refusing to overwrite {}", file_path.display()),
+            ));
+        }
+
+        let input = ureq::get(url)
+            .set("Cookie", &format!("session={}", session_cookie))
+            .set("User-Agent", "github.com/RAvgCoder/AdventOfCode21")
+            .call()
+            .map_err(std::io::Error::other)?
+            .into_string()?;
+
+        File::create_new(file_path)?.write_all(input.as_bytes())
+    }
+
+    /// Reads a file and splits it into groups of lines separated by blank lines.
+    ///
+    /// # Arguments
+    /// * `day_num` - The day number of the puzzle.
+    ///
+    /// # Returns
+    /// * `Vec<Vec<String>>` - The groups of non-empty lines, in file order. Runs of several blank
+    ///   lines never produce empty groups.
+    ///
+    /// # Panics
+    ///  If the file cannot be read.
+    #[allow(dead_code)]
+    pub fn read_file_groups(day_num: u8) -> Vec<Vec<String>> {
+        Self::split_groups(&Self::read_file_raw(day_num))
+    }
+
+    /// Splits `raw` into groups of non-empty lines, using blank lines as separators.
+    fn split_groups(raw: &str) -> Vec<Vec<String>> {
+        let mut groups = vec![];
+        let mut group = vec![];
+        for line in raw.lines() {
+            if line.trim().is_empty() {
+                if !group.is_empty() {
+                    groups.push(std::mem::take(&mut group));
+                }
+            } else {
+                group.push(line.to_string());
+            }
+        }
+        if !group.is_empty() {
+            groups.push(group);
+        }
+
+        groups
+    }
+
+    /// Reads the first line of a file and parses it as a list of comma-separated values.
+    ///
+    /// # Arguments
+    /// * `day_num` - The day number of the puzzle.
+    ///
+    /// # Type Parameters
+    /// * `T` - The type of each comma-separated value.
+    ///
+    /// # Returns
+    /// * `Result<Vec<T>, String>` - The parsed values, or an error naming the first value that failed to parse.
+    ///
+    /// # Panics
+    ///  If the file cannot be read.
+    #[allow(dead_code)]
+    pub fn read_csv<T>(day_num: u8) -> Result<Vec<T>, String>
+    where
+        T: std::str::FromStr,
+        T::Err: Debug,
+    {
+        Self::read_csv_line(&Self::get_input_path(day_num))
+    }
+
+    /// Reads the file at `file_path` and parses its first line as comma-separated values.
+    ///
+    /// # Panics
+    ///  If the file cannot be read.
+    fn read_csv_line<T>(file_path: &Path) -> Result<Vec<T>, String>
+    where
+        T: std::str::FromStr,
+        T::Err: Debug,
+    {
+        Self::parse_csv(Self::read_raw(file_path).lines().next().unwrap_or_default())
+    }
+
+    /// Parses a single line of comma-separated values.
+    ///
+    /// # Arguments
+    /// * `line` - The line to parse. Surrounding whitespace of the line and of each value is ignored.
+    ///
+    /// # Type Parameters
+    /// * `T` - The type of each comma-separated value.
+    ///
+    /// # Returns
+    /// * `Result<Vec<T>, String>` - The parsed values, or an error naming the first value that failed to parse.
+    pub fn parse_csv<T>(line: &str) -> Result<Vec<T>, String>
+    where
+        T: std::str::FromStr,
+        T::Err: Debug,
+    {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(vec![]);
+        }
+
+        line.split(',')
+            .map(|value| {
+                value
+                    .trim()
+                    .parse::<T>()
+                    .map_err(|err| format!("Could not parse '{}': {:?}", value, err))
+            })
+            .collect()
+    }
+
+    /// Reads the file at `file_path` into a `String`.
+    ///
+    /// # Panics
+    ///  If the file cannot be read.
+    fn read_raw(file_path: &Path) -> String {
+        std::fs::read_to_string(file_path)
+            .unwrap_or_else(|_| panic!("Failed to read file at {}", file_path.display()))
+    }
+
+    /// Retrieves the path of the input file for a day, where day `0` is the example input.
+    ///
+    /// # Arguments
+    /// * `day_num` - The day number of the puzzle.
+    ///
+    /// # Returns
+    /// * `PathBuf` - The path to the input file.
+    fn get_input_path(day_num: u8) -> PathBuf {
+        Self::get_file_path()
+            .join("inputs")
+            .join(if day_num == 0 {
+                "Example".to_string()
+            } else {
+                format!("day{}", day_num)
+            })
+            .with_extension("txt")
+    }
+
     /// Retrieves the base directory for the project.
     ///
     /// # Returns
@@ -262,19 +819,21 @@ Found: {:?}
         writeln!(
             file,
             r#"
-use crate::utils::day_setup::Utils;
+use crate::utils::day_setup::{{AssertionError, Utils}};
 
 /// Runs the Advent of Code puzzles for [Current Day](https://adventofcode.com/20{}/day/{}).
 ///
 /// This function calls the `run_part` function from the `Utils` module to execute and time
 /// the solutions for both parts of the current day, checking them against the expected results.
 ///
-/// # Panics
+/// # Errors
 ///   If the result of any part does not match the expected value.
-pub fn run() {{
-    // run_part(day_func_part_to_run, part_num, day_num)
-    Utils::run_part(part1, 1, 0, None);
-    Utils::run_part(part2, 2, 0, None);
+pub fn run() -> Result<(), AssertionError> {{
+    // run_part(day_func_part_to_run, part_num, day_num, expected)
+    Utils::run_part(part1, 1, 0, None)?;
+    Utils::run_part(part2, 2, 0, None)?;
+
+    Ok(())
 }}
 
 fn part1(input: Vec<String>) -> u64 {{
@@ -298,3 +857,300 @@ fn part2(input: Vec<String>) -> u64 {{
         );
     }
 }
+
+#[cfg(test)]
+mod day_setup_tests {
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        Utils::get_file_path()
+            .join("inputs")
+            .join("fixtures")
+            .join(name)
+    }
+
+    #[test]
+    fn test_read_raw() {
+        let raw = Utils::read_raw(&fixture("raw.txt"));
+        assert_eq!(raw, "8A004A801A8002F478\nsecond line\n");
+    }
+
+    #[test]
+    fn test_split_groups() {
+        let groups = Utils::split_groups(&Utils::read_raw(&fixture("groups.txt")));
+        assert_eq!(
+            groups,
+            vec![
+                vec!["NNCB".to_string()],
+                vec!["CH -> B".to_string(), "HH -> N".to_string()],
+                vec!["6,10".to_string(), "0,14".to_string(), "9,10".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        assert_eq!(Utils::parse_csv::<u32>("1,2,3"), Ok(vec![1, 2, 3]));
+        assert_eq!(Utils::parse_csv::<u32>(" 16, 1 ,2\n"), Ok(vec![16, 1, 2]));
+        assert_eq!(Utils::parse_csv::<u32>(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_read_csv() {
+        // Only the first line is read
+        assert_eq!(
+            Utils::read_csv_line::<u32>(&fixture("csv.txt")),
+            Ok(vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14])
+        );
+        assert_eq!(
+            Utils::read_csv_line::<u32>(&fixture("empty.txt")),
+            Ok(vec![])
+        );
+        assert!(Utils::read_csv_line::<u32>(&fixture("raw.txt")).is_err());
+    }
+
+    #[test]
+    fn test_parse_csv_invalid() {
+        let err = Utils::parse_csv::<u8>("3,4,x,1").unwrap_err();
+        assert!(err.starts_with("Could not parse 'x'"), "{}", err);
+        assert!(Utils::parse_csv::<u8>("3,,1").is_err());
+    }
+
+    #[test]
+    fn test_run_part_mismatch() {
+        // The same input reader as `run_part_raw`, pointed at a fixture
+        let raw = || Utils::read_raw(&fixture("raw.txt"));
+
+        let result = Utils::run_part_with(&mut BannerReport, raw, |_| 41, 1, 0, Some(42));
+        assert_eq!(
+            result,
+            Err(AssertionError {
+                expected: "42".to_string(),
+                found: "41".to_string(),
+            })
+        );
+
+        assert_eq!(
+            Utils::run_part_with(&mut BannerReport, raw, |_| "ok", 2, 0, Some("ok")),
+            Ok("ok")
+        );
+        assert_eq!(
+            Utils::run_part_with(&mut BannerReport, raw, |_| 7, 2, 0, None),
+            Ok(7)
+        );
+    }
+
+    fn run_part_json<R: Debug + Display + PartialEq>(
+        day_func_part_to_run: impl FnOnce(Vec<String>) -> R,
+        expected: Option<R>,
+    ) -> (Result<R, AssertionError>, serde_json::Value) {
+        let mut out = Vec::new();
+        let result = Utils::run_part_json_to(
+            &mut out,
+            || Utils::read_lines::<String>(&fixture("groups.txt")),
+            day_func_part_to_run,
+            2,
+            0,
+            expected,
+        );
+
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.lines().count(), 1, "{}", out);
+        (result, serde_json::from_str(&out).unwrap())
+    }
+
+    #[test]
+    fn test_run_part_json() {
+        let (result, json) = run_part_json(|lines| lines.len(), Some(10));
+        assert_eq!(result, Ok(10));
+        assert_eq!(json["day"], 0);
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["result"], "10");
+        assert!(json["micros"].is_u64());
+        assert_eq!(json["passed"], true);
+
+        let (result, json) = run_part_json(|lines| lines.len(), Some(3));
+        assert!(result.is_err());
+        assert_eq!(json["passed"], false);
+
+        let (result, json) = run_part_json(|lines| lines.len(), None);
+        assert_eq!(result, Ok(10));
+        assert_eq!(json["passed"], false);
+    }
+
+    #[test]
+    fn test_run_part_json_escapes_result() {
+        let (_, json) = run_part_json(|lines| lines.join("\n\t\"\\"), None);
+        assert_eq!(
+            json["result"],
+            Utils::read_lines::<String>(&fixture("groups.txt")).join("\n\t\"\\")
+        );
+    }
+
+    #[test]
+    fn test_run_part_json_str_result() {
+        let (result, json) = run_part_json(|_| "UEFZCUCJ", Some("UEFZCUCJ"));
+        assert_eq!(result, Ok("UEFZCUCJ"));
+        assert_eq!(json["result"], "UEFZCUCJ");
+        assert_eq!(json["passed"], true);
+
+        let (result, json) = run_part_json(|_| "UEFZCUCJ".to_string(), Some("HELLO".to_string()));
+        assert_eq!(json["result"], "UEFZCUCJ");
+        assert_eq!(json["passed"], false);
+        assert_eq!(
+            result,
+            Err(AssertionError {
+                expected: r#""HELLO""#.to_string(),
+                found: r#""UEFZCUCJ""#.to_string(),
+            })
+        );
+    }
+
+    #[derive(Debug)]
+    struct LineCount(usize);
+
+    impl From<Vec<String>> for LineCount {
+        fn from(lines: Vec<String>) -> Self {
+            LineCount(lines.len())
+        }
+    }
+
+    #[test]
+    fn test_run_part_str_result() {
+        // The same input readers as `run_part` and `run_part_single`, pointed at fixtures
+        let lines = |name| move || Utils::read_lines::<String>(&fixture(name));
+        let line_count = |name| move || LineCount::from(Utils::read_lines(&fixture(name)));
+
+        let name = |lines: Vec<String>| if lines.is_empty() { "empty" } else { "groups" };
+        assert_eq!(
+            Utils::run_part_with(
+                &mut BannerReport,
+                lines("groups.txt"),
+                name,
+                1,
+                0,
+                Some("groups")
+            ),
+            Ok("groups")
+        );
+        assert_eq!(
+            Utils::run_part_with(
+                &mut BannerReport,
+                lines("empty.txt"),
+                name,
+                1,
+                0,
+                Some("empty")
+            ),
+            Ok("empty")
+        );
+
+        let name = |count: LineCount| if count.0 == 10 { "groups" } else { "other" };
+        assert_eq!(
+            Utils::run_part_with(
+                &mut BannerReport,
+                line_count("groups.txt"),
+                name,
+                2,
+                0,
+                Some("groups")
+            ),
+            Ok("groups")
+        );
+        assert_eq!(
+            Utils::run_part_with(
+                &mut BannerReport,
+                line_count("empty.txt"),
+                name,
+                2,
+                0,
+                Some("groups")
+            ),
+            Err(AssertionError {
+                expected: r#""groups""#.to_string(),
+                found: r#""other""#.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_bench_part() {
+        let calls = std::cell::Cell::new(0);
+        let count_calls = |lines: Vec<String>| {
+            assert_eq!(lines.len(), 10);
+            calls.set(calls.get() + 1);
+        };
+        let lines = || Utils::read_lines::<String>(&fixture("groups.txt"));
+
+        let stats = Utils::bench_part_with(lines, count_calls, 1, 0, 5).unwrap();
+        assert_eq!(calls.get(), 5);
+        assert_eq!(stats.runs, 5);
+        assert!(stats.min <= stats.median);
+
+        assert!(Utils::bench_part_with(lines, count_calls, 1, 0, 0).is_err());
+        assert_eq!(calls.get(), 5);
+    }
+
+    #[test]
+    fn test_run_all() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn passing_day() -> Result<(), AssertionError> {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+        fn failing_day() -> Result<(), AssertionError> {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            Err(AssertionError {
+                expected: "1".to_string(),
+                found: "2".to_string(),
+            })
+        }
+
+        let summary = Utils::run_all(&[passing_day, failing_day, passing_day]);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+        assert_eq!(summary.ran, 3);
+        assert_eq!(summary.failures.len(), 1);
+        assert_eq!(summary.failures[0].0, 1);
+    }
+
+    #[cfg(feature = "net")]
+    #[test]
+    fn test_download_input() {
+        use std::io::Read;
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/2021/day/1/input", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let len = stream.read(&mut request).unwrap();
+            let body = "199\n200\n";
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            String::from_utf8_lossy(&request[..len]).into_owned()
+        });
+
+        let file_path = env::temp_dir().join(format!("aoc21_download_{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&file_path);
+
+        Utils::download_input_to(&url, "secret", &file_path).unwrap();
+        let request = server.join().unwrap();
+        assert!(request.contains("session=secret"), "{}", request);
+        assert_eq!(Utils::read_raw(&file_path), "199\n200\n");
+
+        // The server is gone, so this would fail if a request was attempted
+        let err = Utils::download_input_to(&url, "secret", &file_path).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+        assert_eq!(Utils::read_raw(&file_path), "199\n200\n");
+
+        std::fs::remove_file(&file_path).unwrap();
+    }
+}
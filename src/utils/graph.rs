@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::fmt::Formatter;
 
 /// A graph data structure where nodes and edges are stored in vectors.
@@ -17,7 +18,8 @@ use std::fmt::Formatter;
 ///
 /// # Disadvantages
 /// - Removing nodes or edges from the graph can be problematic, as it may lead to "dangling indices"
-///   or require a placeholder, similar to issues with `malloc`/`free`. `(For now removal is not implemented.)`
+///   or require a placeholder, similar to issues with `malloc`/`free`. Removed nodes are therefore kept
+///   as tombstones (see [`Graph::remove_node`]).
 /// - Indices from one graph should not be used with another graph to avoid misuse.
 ///
 /// # Type Parameters
@@ -38,14 +40,14 @@ use std::fmt::Formatter;
 /// let edge_data = ();
 ///
 /// // Add edges between nodes
-/// graph.add_edge(node_a, node_b, edge_data);
-/// graph.add_edge(node_b, node_c, edge_data);
+/// graph.add_edge(node_a.clone(), node_b.clone(), edge_data);
+/// graph.add_edge(node_b, node_c.clone(), edge_data);
 /// graph.add_edge(node_c, node_a, edge_data);
 ///
 /// // Find a node by data
-/// if let Some(node_index) = graph.find_node_index(|node: &&str| node == &"B") {
+/// if let Some(node_ptr) = graph.find_node_index(|node: &&str| node == &"B") {
 ///     // Retrieve and print the data of the found node
-///     let node_data = graph.get_node_data(node_index);
+///     let node_data = graph.get(&node_ptr);
 ///     println!("Node data: {}", node_data);
 /// }
 ///
@@ -74,7 +76,8 @@ pub struct NodePtr {
 /// * `N` - The type of data stored in the node.
 #[derive(Debug)]
 struct Node<N> {
-    data: N,
+    /// `None` once the node has been removed from the graph.
+    data: Option<N>,
     node_index: NodePtr,
     first_edge: Option<EdgePtr>,
 }
@@ -116,8 +119,12 @@ impl<N, E> Graph<N, E> {
         }
     }
 
+    #[allow(dead_code)]
     pub fn nodes(&self) -> Vec<&N> {
-        self.nodes.iter().map(|node| &node.data).collect::<Vec<_>>()
+        self.nodes
+            .iter()
+            .filter_map(|node| node.data.as_ref())
+            .collect::<Vec<_>>()
     }
 
     /// Finds the index of a node containing the specified data.
@@ -129,7 +136,7 @@ impl<N, E> Graph<N, E> {
     ///
     /// # Returns
     ///
-    /// An `Option` containing the `NodeIndex` if found, or `None` if not found.
+    /// An `Option` containing the `NodePtr` if found, or `None` if not found.
     pub fn find_node_index<F>(&self, find_fn: F) -> Option<NodePtr>
     where
         N: PartialEq + Eq,
@@ -137,15 +144,15 @@ impl<N, E> Graph<N, E> {
     {
         self.nodes
             .iter()
-            .find(|node| find_fn(&node.data))
+            .find(|node| node.data.as_ref().is_some_and(&find_fn))
             .map(|node| node.node_index.clone())
     }
 
     /// # Returns
     ///
-    /// Gets the number of nodes in the graph.
+    /// Gets the number of nodes in the graph, excluding removed nodes.
     pub fn len(&self) -> usize {
-        self.nodes.len()
+        self.nodes.iter().filter(|node| node.data.is_some()).count()
     }
 
     /// Gets a reference to the data stored in the node at the specified index.
@@ -157,8 +164,15 @@ impl<N, E> Graph<N, E> {
     /// # Returns
     ///
     /// A reference to the data stored in the node.
+    ///
+    /// # Panics
+    ///
+    /// If the node has been removed from the graph.
     pub fn get(&self, node_index: &NodePtr) -> &N {
-        &self.nodes[node_index.idx].data
+        self.nodes[node_index.idx]
+            .data
+            .as_ref()
+            .expect("Node has been removed from the graph")
     }
 
     /// Gets a mutable reference to the data stored in the node at the specified index.
@@ -170,9 +184,16 @@ impl<N, E> Graph<N, E> {
     /// # Returns
     ///
     /// A mutable reference to the data stored in the node.
+    ///
+    /// # Panics
+    ///
+    /// If the node has been removed from the graph.
     #[allow(dead_code)]
     pub fn get_mut(&mut self, node_index: NodePtr) -> &mut N {
-        &mut self.nodes[node_index.idx].data
+        self.nodes[node_index.idx]
+            .data
+            .as_mut()
+            .expect("Node has been removed from the graph")
     }
 
     /// Adds a new node with the specified data to the graph.
@@ -183,13 +204,13 @@ impl<N, E> Graph<N, E> {
     ///
     /// # Returns
     ///
-    /// The `NodeIndex` of the newly added node.
+    /// The `NodePtr` of the newly added node.
     pub fn add_node(&mut self, data: N) -> NodePtr {
         let node_index = NodePtr {
             idx: self.nodes.len(),
         };
         self.nodes.push(Node {
-            data,
+            data: Some(data),
             node_index: node_index.clone(),
             first_edge: None,
         });
@@ -197,6 +218,32 @@ impl<N, E> Graph<N, E> {
         node_index
     }
 
+    /// Removes a node and all of its edges from the graph.
+    ///
+    /// The node is left behind as a tombstone so that every other `NodePtr` and `EdgePtr` stays
+    /// valid. Edges from other nodes that point to the removed node are skipped by
+    /// [`Graph::neighbours_iter`]. Any `NodePtr` to the removed node becomes invalid and must not
+    /// be passed to [`Graph::get`] or [`Graph::get_mut`] again.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_index` - The index of the node to remove.
+    ///
+    /// # Returns
+    ///
+    /// The data of the removed node, or `None` if it had already been removed.
+    #[allow(dead_code)]
+    pub fn remove_node(&mut self, node_index: NodePtr) -> Option<N> {
+        let node = &mut self.nodes[node_index.idx];
+        node.first_edge = None;
+        node.data.take()
+    }
+
+    /// Returns `true` if the node has been removed from the graph.
+    fn is_removed(&self, node_index: &NodePtr) -> bool {
+        self.nodes[node_index.idx].data.is_none()
+    }
+
     /// Adds a new edge between two nodes in the graph.
     ///
     /// # Arguments
@@ -204,7 +251,15 @@ impl<N, E> Graph<N, E> {
     /// * `from` - The index of the source node.
     /// * `to` - The index of the destination node.
     /// * `edge_data` - The data to store in the new edge.
+    ///
+    /// # Panics
+    ///
+    /// If either node has been removed from the graph.
     pub fn add_edge(&mut self, from: NodePtr, to: NodePtr, edge_data: E) {
+        assert!(
+            !self.is_removed(&from) && !self.is_removed(&to),
+            "Cannot add an edge to a node that has been removed from the graph"
+        );
         let new_edge_index = Some(EdgePtr {
             idx: self.edges.len(),
         });
@@ -216,14 +271,32 @@ impl<N, E> Graph<N, E> {
         self.nodes[from.idx].first_edge = new_edge_index;
     }
 
+    /// Adds an undirected edge between two nodes, stored as the two edges `a -> b` and `b -> a`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The index of the first node.
+    /// * `b` - The index of the second node.
+    /// * `edge_data` - The data to store in both edges.
+    #[allow(dead_code)]
+    pub fn add_bidirectional_edge(&mut self, a: NodePtr, b: NodePtr, edge_data: E)
+    where
+        E: Clone,
+    {
+        self.add_edge(a.clone(), b.clone(), edge_data.clone());
+        self.add_edge(b, a, edge_data);
+    }
+
     /// Adds a new edge between two nodes, identified by their data.
     ///
+    /// Nodes that are not yet part of the graph are added first.
+    ///
     /// # Arguments
     ///
-    /// * `from` - The data of the source node.
-    /// * `to` - The data of the destination node.
-    /// * `edge_data` - The data to store in the new edge.
-    pub fn add_edge_by_data(&mut self, node_a: N, node_b: N, relatoinship: Relationship<E>)
+    /// * `node_a` - The data of the first node.
+    /// * `node_b` - The data of the second node.
+    /// * `relationship` - The direction(s) of the edge(s) to add, together with their data.
+    pub fn add_edge_by_data(&mut self, node_a: N, node_b: N, relationship: Relationship<E>)
     where
         N: PartialEq + Eq,
     {
@@ -237,7 +310,7 @@ impl<N, E> Graph<N, E> {
             Some(node_index) => node_index,
         };
 
-        match relatoinship {
+        match relationship {
             Relationship::BiDirectional { a_to_b, b_to_a } => {
                 self.add_edge(a_index.clone(), b_index.clone(), a_to_b);
                 self.add_edge(b_index, a_index, b_to_a);
@@ -255,12 +328,271 @@ impl<N, E> Graph<N, E> {
         &self.edges[edge_index.idx]
     }
 
-    pub fn neighbours_iter(&self, node_index: &NodePtr) -> Neighbours<N, E> {
+    pub fn neighbours_iter(&self, node_index: &NodePtr) -> Neighbours<'_, N, E> {
         Neighbours {
             graph: self,
             edges: self.nodes[node_index.idx].first_edge.clone(),
         }
     }
+
+    /// Returns the outgoing neighbours of a node along with the data of the edge leading to each.
+    ///
+    /// This is [`Graph::neighbours_iter`] with owned node indices, which is handier for weighted
+    /// algorithms that push the neighbours into their own queues.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_index` - The index of the node whose neighbours are returned.
+    ///
+    /// # Returns
+    ///
+    /// An iterator of `(neighbour, edge data)` pairs, most recently added edge first.
+    #[allow(dead_code)]
+    pub fn neighbours_with_edges(
+        &self,
+        node_index: &NodePtr,
+    ) -> impl Iterator<Item = (NodePtr, &E)> {
+        self.neighbours_iter(node_index)
+            .map(|(neighbour, edge_data)| (neighbour.clone(), edge_data))
+    }
+
+    /// Walks the graph breadth-first, starting at `start`.
+    ///
+    /// Every reachable node is yielded exactly once, level by level. Within a level, nodes are
+    /// yielded in the order [`Graph::neighbours_iter`] returns them.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The index of the node to start from.
+    ///
+    /// # Returns
+    ///
+    /// A `Bfs` iterator over the indices of the reachable nodes, starting with `start`.
+    #[allow(dead_code)]
+    pub fn bfs(&self, start: NodePtr) -> Bfs<'_, N, E> {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        if !self.is_removed(&start) {
+            visited.insert(start.clone());
+            queue.push_back(start);
+        }
+
+        Bfs {
+            graph: self,
+            visited,
+            queue,
+        }
+    }
+
+    /// Walks the graph depth-first, starting at `start`, using an explicit stack.
+    ///
+    /// Every reachable node is yielded exactly once, in pre-order. Since edges are pushed to the
+    /// front of each node's adjacency list, [`Graph::neighbours_iter`] yields the most recently
+    /// added edge first. Pushing those neighbours onto the stack reverses them again, so the
+    /// children of a node are explored in the order their edges were **added**.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The index of the node to start from.
+    ///
+    /// # Returns
+    ///
+    /// A `Dfs` iterator over the indices of the reachable nodes, starting with `start`.
+    #[allow(dead_code)]
+    pub fn dfs(&self, start: NodePtr) -> Dfs<'_, N, E> {
+        let mut stack = Vec::new();
+        if !self.is_removed(&start) {
+            stack.push(start);
+        }
+
+        Dfs {
+            graph: self,
+            visited: HashSet::new(),
+            stack,
+        }
+    }
+
+    /// Finds the cost of the cheapest path between two nodes using Dijkstra's algorithm.
+    ///
+    /// The edge data is used as the (non-negative) weight of each edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The index of the source node.
+    /// * `to` - The index of the destination node.
+    ///
+    /// # Returns
+    ///
+    /// The total weight of the cheapest path, or `None` if `to` is unreachable from `from`.
+    #[allow(dead_code)]
+    pub fn shortest_path(&self, from: NodePtr, to: NodePtr) -> Option<u64>
+    where
+        E: Into<u64> + Copy,
+    {
+        let mut min_cost = vec![u64::MAX; self.nodes.len()];
+        let mut heap = BinaryHeap::<Reverse<(u64, usize)>>::new();
+        min_cost[from.idx] = 0;
+        heap.push(Reverse((0, from.idx)));
+
+        while let Some(Reverse((cost, idx))) = heap.pop() {
+            if idx == to.idx {
+                return Some(cost);
+            }
+            if cost > min_cost[idx] {
+                continue;
+            }
+
+            for (neighbour, &weight) in self.neighbours_iter(&NodePtr { idx }) {
+                let new_cost = cost + weight.into();
+                if new_cost < min_cost[neighbour.idx] {
+                    min_cost[neighbour.idx] = new_cost;
+                    heap.push(Reverse((new_cost, neighbour.idx)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Enumerates every path from `start` to `end`.
+    ///
+    /// A node that is not yet on the current path can always be entered. A node already on the
+    /// path may only be entered again if `can_revisit` allows it, which makes this usable for both
+    /// simple paths (`|_, _| false`) and looser rules such as AoC day12's cave rules. Paths stop as
+    /// soon as they reach `end`.
+    ///
+    /// `can_revisit` must eventually refuse every node, otherwise a cycle is walked forever.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The index of the node every path starts at.
+    /// * `end` - The index of the node every path ends at.
+    /// * `can_revisit` - Takes the data of a node that is already on the path and the current path
+    ///   (from `start` up to the node being left) and returns whether the node may be entered again.
+    ///
+    /// # Returns
+    ///
+    /// Every path found, each including both `start` and `end`.
+    pub fn all_simple_paths<F>(
+        &self,
+        start: NodePtr,
+        end: NodePtr,
+        can_revisit: F,
+    ) -> Vec<Vec<NodePtr>>
+    where
+        F: Fn(&N, &[NodePtr]) -> bool,
+    {
+        fn walk<N, E, F>(
+            graph: &Graph<N, E>,
+            end: &NodePtr,
+            can_revisit: &F,
+            path: &mut Vec<NodePtr>,
+            paths: &mut Vec<Vec<NodePtr>>,
+        ) where
+            F: Fn(&N, &[NodePtr]) -> bool,
+        {
+            let curr = path.last().unwrap().clone();
+            if curr == *end {
+                paths.push(path.clone());
+                return;
+            }
+
+            for (neighbour, _) in graph.neighbours_iter(&curr) {
+                if path.contains(neighbour) && !can_revisit(graph.get(neighbour), path) {
+                    continue;
+                }
+                path.push(neighbour.clone());
+                walk(graph, end, can_revisit, path, paths);
+                path.pop();
+            }
+        }
+
+        let mut paths = Vec::new();
+        walk(self, &end, &can_revisit, &mut vec![start], &mut paths);
+
+        paths
+    }
+
+    /// Groups the nodes of the graph into weakly connected components.
+    ///
+    /// Edges are treated as undirected, so two nodes end up in the same component if there is
+    /// any path between them ignoring edge direction. Components are found with a union-find
+    /// over every edge in the graph.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of components, each a `Vec` of node indices in insertion order. Components are
+    /// ordered by their first node. Removed nodes are not part of any component.
+    #[allow(dead_code)]
+    pub fn weakly_connected_components(&self) -> Vec<Vec<NodePtr>> {
+        fn find_root(parents: &mut [usize], mut idx: usize) -> usize {
+            while parents[idx] != idx {
+                parents[idx] = parents[parents[idx]];
+                idx = parents[idx];
+            }
+            idx
+        }
+
+        let mut parents = (0..self.nodes.len()).collect::<Vec<_>>();
+        for node in self.nodes.iter() {
+            for (neighbour, _) in self.neighbours_iter(&node.node_index) {
+                let a = find_root(&mut parents, node.node_index.idx);
+                let b = find_root(&mut parents, neighbour.idx);
+                parents[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut components: Vec<Vec<NodePtr>> = Vec::new();
+        let mut component_of_root = HashMap::new();
+        for node in self.nodes.iter().filter(|node| node.data.is_some()) {
+            let root = find_root(&mut parents, node.node_index.idx);
+            let component = *component_of_root.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[component].push(node.node_index.clone());
+        }
+
+        components
+    }
+
+    /// Exports the graph in the Graphviz DOT format.
+    ///
+    /// Every node is labelled with its `Debug` representation and every edge is emitted on its own
+    /// line as `"from" -> "to" [label="edge"]`.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing a `digraph { ... }` that can be pasted into any DOT viewer.
+    #[allow(dead_code)]
+    pub fn to_dot(&self) -> String
+    where
+        N: std::fmt::Debug,
+        E: std::fmt::Debug,
+    {
+        fn escape(debug: String) -> String {
+            debug.replace('\\', "\\\\").replace('"', "\\\"")
+        }
+
+        let mut dot = String::from("digraph {\n");
+        for node in self.nodes.iter() {
+            let Some(data) = &node.data else {
+                continue;
+            };
+            let from = escape(format!("{:?}", data));
+            dot.push_str(&format!("    \"{from}\";\n"));
+            for (neighbour, edge) in self.neighbours_iter(&node.node_index) {
+                let to = escape(format!("{:?}", self.get(neighbour)));
+                let label = escape(format!("{:?}", edge));
+                dot.push_str(&format!(
+                    "    \"{from}\" -> \"{to}\" [label=\"{label}\"];\n"
+                ));
+            }
+        }
+        dot.push('}');
+
+        dot
+    }
 }
 
 pub struct Neighbours<'a, N, E> {
@@ -275,11 +607,65 @@ where
     type Item = (&'a NodePtr, &'a E);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.edges.clone().map(|edge_index| {
+        while let Some(edge_index) = self.edges.clone() {
             let edge = self.graph.get_edge(edge_index);
             self.edges = edge.next_edge.clone();
-            (&edge.to, &edge.data)
-        })
+            if !self.graph.is_removed(&edge.to) {
+                return Some((&edge.to, &edge.data));
+            }
+        }
+        None
+    }
+}
+
+/// A breadth-first iterator over the nodes of a graph, created by [`Graph::bfs`].
+pub struct Bfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    visited: HashSet<NodePtr>,
+    queue: VecDeque<NodePtr>,
+}
+
+impl<N, E> Iterator for Bfs<'_, N, E> {
+    type Item = NodePtr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node_index = self.queue.pop_front()?;
+        for (neighbour, _) in self.graph.neighbours_iter(&node_index) {
+            if self.visited.insert(neighbour.clone()) {
+                self.queue.push_back(neighbour.clone());
+            }
+        }
+
+        Some(node_index)
+    }
+}
+
+/// A depth-first iterator over the nodes of a graph, created by [`Graph::dfs`].
+pub struct Dfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    visited: HashSet<NodePtr>,
+    stack: Vec<NodePtr>,
+}
+
+impl<N, E> Iterator for Dfs<'_, N, E> {
+    type Item = NodePtr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node_index) = self.stack.pop() {
+            if !self.visited.insert(node_index.clone()) {
+                continue;
+            }
+            self.stack.extend(
+                self.graph
+                    .neighbours_iter(&node_index)
+                    .map(|(neighbour, _)| neighbour)
+                    .filter(|neighbour| !self.visited.contains(neighbour))
+                    .cloned(),
+            );
+
+            return Some(node_index);
+        }
+        None
     }
 }
 
@@ -299,31 +685,32 @@ where
     /// A `Result` indicating success or failure.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut visited = Vec::with_capacity(self.nodes.len());
-        writeln!(f, "Graph: ({} nodes) {{", self.nodes.len())?;
+        writeln!(f, "Graph: ({} nodes) {{", self.len())?;
         for nodes in self.nodes.iter() {
+            let Some(data) = &nodes.data else {
+                continue;
+            };
             if !visited.contains(&nodes.node_index) {
                 let mut curr_edge = nodes.first_edge.clone();
                 if curr_edge.is_none() {
                     writeln!(
                         f,
                         "\tNode: ({:?}) (Data: '{:?}') : []",
-                        nodes.node_index, nodes.data
+                        nodes.node_index, data
                     )?;
                     continue;
                 }
                 writeln!(
                     f,
                     "\tNode: ({:?}) (Data: '{:?}') : [",
-                    nodes.node_index, nodes.data
+                    nodes.node_index, data
                 )?;
                 while let Some(edge_index) = curr_edge.clone() {
                     let edge = &self.edges[edge_index.idx];
-                    writeln!(
-                        f,
-                        "\t\tEdge: '{:?}' ->  To: '{:?}'",
-                        edge.data, self.nodes[edge.to.idx].data
-                    )?;
                     curr_edge = edge.next_edge.clone();
+                    if let Some(to) = &self.nodes[edge.to.idx].data {
+                        writeln!(f, "\t\tEdge: '{:?}' ->  To: '{:?}'", edge.data, to)?;
+                    }
                 }
                 writeln!(f, "\t]")?;
                 visited.push(nodes.node_index.clone())
@@ -339,6 +726,7 @@ where
 /// # Type Parameters
 /// * `E` - The type of data stored in the edges.
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub enum Relationship<E> {
     /// A bidirectional relationship between two nodes.
     /// Contains data for both directions (a->b and b->a).
@@ -428,3 +816,252 @@ where
         graph
     }
 }
+
+#[cfg(test)]
+mod graph_tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_node() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        let c = graph.add_node("C");
+        graph.add_edge(a.clone(), b.clone(), ());
+        graph.add_edge(a.clone(), c.clone(), ());
+        graph.add_edge(b.clone(), c.clone(), ());
+
+        assert_eq!(graph.remove_node(b.clone()), Some("B"));
+        assert_eq!(graph.remove_node(b.clone()), None);
+
+        let neighbours = graph
+            .neighbours_iter(&a)
+            .map(|(node, _)| node.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(neighbours, vec![c.clone()]);
+        assert_eq!(graph.neighbours_iter(&b).count(), 0);
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.nodes(), vec![&"A", &"C"]);
+        assert_eq!(graph.find_node_index(|node| node == &"B"), None);
+        assert_eq!(graph.get(&c), &"C");
+        assert!(format!("{:?}", graph).starts_with("Graph: (2 nodes) {"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_edge_to_removed_node() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        graph.remove_node(b.clone());
+
+        graph.add_edge(a, b, ());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_edge_from_removed_node() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        graph.remove_node(a.clone());
+
+        graph.add_edge(a, b, ());
+    }
+
+    #[test]
+    fn test_bfs() {
+        // A diamond: A -> {B, C} -> D
+        let mut graph = Graph::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        let c = graph.add_node("C");
+        let d = graph.add_node("D");
+        graph.add_edge(a.clone(), b.clone(), ());
+        graph.add_edge(a.clone(), c.clone(), ());
+        graph.add_edge(b.clone(), d.clone(), ());
+        graph.add_edge(c.clone(), d.clone(), ());
+
+        let order = graph.bfs(a.clone()).collect::<Vec<_>>();
+        assert_eq!(order.len(), 4);
+        assert_eq!(order[0], a);
+        assert_eq!(
+            order[1..3].iter().cloned().collect::<HashSet<_>>(),
+            HashSet::from([b, c])
+        );
+        assert_eq!(order[3], d);
+
+        assert_eq!(graph.bfs(d.clone()).collect::<Vec<_>>(), vec![d]);
+    }
+
+    #[test]
+    fn test_dfs() {
+        //        root
+        //       /    \
+        //      x      y
+        //     / \     |
+        //    x1  x2   y1
+        let mut graph = Graph::new();
+        let root = graph.add_node("root");
+        let x = graph.add_node("x");
+        let y = graph.add_node("y");
+        let x1 = graph.add_node("x1");
+        let x2 = graph.add_node("x2");
+        let y1 = graph.add_node("y1");
+        graph.add_edge(root.clone(), x.clone(), ());
+        graph.add_edge(root.clone(), y.clone(), ());
+        graph.add_edge(x.clone(), x1.clone(), ());
+        graph.add_edge(x.clone(), x2.clone(), ());
+        graph.add_edge(y.clone(), y1.clone(), ());
+
+        let order = graph
+            .dfs(root)
+            .map(|node| *graph.get(&node))
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["root", "x", "x1", "x2", "y", "y1"]);
+    }
+
+    #[test]
+    fn test_neighbours_with_edges() {
+        let mut graph = Graph::<&str, u8>::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        let c = graph.add_node("C");
+        let d = graph.add_node("D");
+        graph.add_edge(a.clone(), b.clone(), 1);
+        graph.add_edge(a.clone(), c.clone(), 4);
+        graph.add_edge(a.clone(), d.clone(), 7);
+        graph.add_edge(b.clone(), a.clone(), 2);
+
+        let neighbours = graph.neighbours_with_edges(&a).collect::<Vec<_>>();
+        assert_eq!(neighbours, vec![(d.clone(), &7), (c, &4), (b.clone(), &1)]);
+        assert_eq!(
+            graph.neighbours_with_edges(&b).collect::<Vec<_>>(),
+            vec![(a, &2)]
+        );
+        assert_eq!(graph.neighbours_with_edges(&d).count(), 0);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        // A -1-> B -10-> D
+        // A -4-> C  -2-> D
+        let mut graph = Graph::<&str, u8>::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        let c = graph.add_node("C");
+        let d = graph.add_node("D");
+        graph.add_edge(a.clone(), b.clone(), 1);
+        graph.add_edge(b.clone(), d.clone(), 10);
+        graph.add_edge(a.clone(), c.clone(), 4);
+        graph.add_edge(c.clone(), d.clone(), 2);
+
+        assert_eq!(graph.shortest_path(a.clone(), d.clone()), Some(6));
+        assert_eq!(graph.shortest_path(a.clone(), b.clone()), Some(1));
+        assert_eq!(graph.shortest_path(a.clone(), a.clone()), Some(0));
+        assert_eq!(graph.shortest_path(d, a), None);
+    }
+
+    #[test]
+    fn test_weakly_connected_components() {
+        let mut graph = Graph::new();
+        let nodes = ["a", "b", "c", "d", "e", "f"].map(|node| graph.add_node(node));
+        let [a, b, c, d, e, f] = nodes.clone();
+        // A directed cycle and a triangle that is only weakly connected
+        graph.add_edge(a.clone(), b.clone(), ());
+        graph.add_edge(b.clone(), c.clone(), ());
+        graph.add_edge(c.clone(), a.clone(), ());
+        graph.add_edge(d.clone(), e.clone(), ());
+        graph.add_edge(f.clone(), e.clone(), ());
+        graph.add_edge(d.clone(), f.clone(), ());
+
+        let components = graph.weakly_connected_components();
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0], vec![a, b, c]);
+        assert_eq!(components[1], vec![d, e, f.clone()]);
+
+        graph.remove_node(f);
+        assert_eq!(
+            graph
+                .weakly_connected_components()
+                .iter()
+                .map(Vec::len)
+                .collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = Graph::new();
+        let start = graph.add_node(String::from("start"));
+        let end = graph.add_node(String::from("end"));
+        graph.add_edge(start, end, 7);
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph {"));
+        assert!(dot.ends_with('}'));
+        assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+        assert!(dot.contains(r#""\"start\"";"#));
+        assert!(dot.contains(r#""\"end\"";"#));
+        assert!(dot.contains(r#""\"start\"" -> "\"end\"" [label="7"];"#));
+    }
+
+    #[test]
+    fn test_add_bidirectional_edge() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_bidirectional_edge(a.clone(), b.clone(), "a-b");
+
+        assert_eq!(
+            graph.neighbours_iter(&a).collect::<Vec<_>>(),
+            vec![(&b, &"a-b")]
+        );
+        assert_eq!(
+            graph.neighbours_iter(&b).collect::<Vec<_>>(),
+            vec![(&a, &"a-b")]
+        );
+    }
+
+    #[test]
+    fn test_all_simple_paths() {
+        let bidirectional = || Relationship::BiDirectional {
+            a_to_b: (),
+            b_to_a: (),
+        };
+        let graph = Graph::from([
+            ("start", "A", bidirectional()),
+            ("start", "b", bidirectional()),
+            ("A", "c", bidirectional()),
+            ("A", "b", bidirectional()),
+            ("b", "d", bidirectional()),
+            ("A", "end", bidirectional()),
+            ("b", "end", bidirectional()),
+        ]);
+        let start = graph.find_node_index(|&cave| cave == "start").unwrap();
+        let end = graph.find_node_index(|&cave| cave == "end").unwrap();
+        let is_big = |cave: &&str| cave.chars().all(|c| c.is_ascii_uppercase());
+
+        let once = graph.all_simple_paths(start.clone(), end.clone(), |cave, _| is_big(cave));
+        assert_eq!(once.len(), 10);
+        assert!(once
+            .iter()
+            .all(|path| path[0] == start && path.last() == Some(&end)));
+
+        let one_small_twice = graph.all_simple_paths(start.clone(), end.clone(), |cave, path| {
+            if is_big(cave) {
+                return true;
+            }
+            let small_caves = path
+                .iter()
+                .filter(|&node| !is_big(graph.get(node)))
+                .collect::<Vec<_>>();
+            *cave != "start"
+                && small_caves.len() == small_caves.iter().collect::<HashSet<_>>().len()
+        });
+        assert_eq!(one_small_twice.len(), 36);
+
+        assert_eq!(graph.all_simple_paths(start, end, |_, _| false).len(), 4);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::utils::coordinate_system::Coordinate;
-use crate::utils::grid::iterators::GridIter;
+use crate::utils::grid::iterators::{ColIter, GridColIter, GridIter};
+use crate::utils::grid::unsized_grid::UnsizedGrid;
+use std::collections::{HashSet, VecDeque};
 
 mod grid_slice;
 pub mod sized_grid;
@@ -37,6 +39,33 @@ pub trait Grid<T> {
         T: 'a,
         Self: Sized;
 
+    /// Returns an iterator over the elements of a single column, from the top row down.
+    ///
+    /// # Arguments
+    /// * `col` - The index of the column. An out-of-range column yields nothing.
+    ///
+    /// # Returns
+    /// A `ColIter` that yields the coordinate and element of each cell in the column.
+    fn col_iter<'a>(&'a self, col: usize) -> ColIter<'a, Self, T>
+    where
+        T: 'a,
+        Self: Sized,
+    {
+        ColIter::new(self, col)
+    }
+
+    /// Returns an iterator over the columns of the grid, from left to right.
+    ///
+    /// # Returns
+    /// A `GridColIter` that yields a `ColIter` for each column.
+    fn cols<'a>(&'a self) -> GridColIter<'a, Self, T>
+    where
+        T: 'a,
+        Self: Sized,
+    {
+        GridColIter::new(self)
+    }
+
     /// Returns the coordinate of the last element in the grid.
     ///
     /// # Returns
@@ -45,21 +74,148 @@ pub trait Grid<T> {
         Coordinate::new((self.num_rows() - 1) as i32, (self.num_cols() - 1) as i32)
     }
 
-    /// Applies a function to each element in the grid.
+    /// Returns an iterator over the in-bounds orthogonal neighbours of `position`.
     ///
     /// # Type Parameters
     /// * `'a` - The lifetime of the references to the grid and its elements.
+    ///
+    /// # Arguments
+    /// * `position` - The coordinate whose neighbours are yielded.
+    ///
+    /// # Returns
+    /// An iterator of each valid neighbouring coordinate paired with a reference to its element,
+    /// in the order given by `Direction::direction_list()`.
+    fn neighbours<'a>(&'a self, position: Coordinate) -> impl Iterator<Item = (Coordinate, &'a T)>
+    where
+        T: 'a,
+    {
+        position
+            .neighbours()
+            .filter_map(move |neighbour| self.get(&neighbour).map(|e| (neighbour, e)))
+    }
+
+    /// Returns an iterator over the in-bounds neighbours of `position`, including diagonals.
+    ///
+    /// # Type Parameters
+    /// * `'a` - The lifetime of the references to the grid and its elements.
+    ///
+    /// # Arguments
+    /// * `position` - The coordinate whose neighbours are yielded.
+    ///
+    /// # Returns
+    /// An iterator of each valid neighbouring coordinate paired with a reference to its element,
+    /// in the order given by `FullDirection::full_direction_list()`.
+    fn neighbours_8<'a>(&'a self, position: Coordinate) -> impl Iterator<Item = (Coordinate, &'a T)>
+    where
+        T: 'a,
+    {
+        position
+            .neighbours_8()
+            .filter_map(move |neighbour| self.get(&neighbour).map(|e| (neighbour, e)))
+    }
+
+    /// Finds every cell reachable from `start` by orthogonal steps through cells satisfying `can_enter`.
+    ///
+    /// # Type Parameters
+    /// * `F` - The type of the predicate deciding whether a cell may be entered.
+    ///
+    /// # Arguments
+    /// * `start` - The coordinate to start filling from.
+    /// * `can_enter` - Returns `true` if the element may be part of the filled region.
+    ///
+    /// # Returns
+    /// The set of filled coordinates, including `start`. Empty if `start` is out of bounds
+    /// or cannot be entered itself.
+    fn flood_fill<F>(&self, start: Coordinate, can_enter: F) -> HashSet<Coordinate>
+    where
+        F: Fn(&T) -> bool,
+        Self: Sized,
+    {
+        let mut filled = HashSet::new();
+        if !self.get(&start).is_some_and(&can_enter) {
+            return filled;
+        }
+
+        let mut queue = VecDeque::from([start]);
+        filled.insert(start);
+        while let Some(position) = queue.pop_front() {
+            for (neighbour, e) in self.neighbours(position) {
+                if can_enter(e) && filled.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        filled
+    }
+
+    /// Creates a new grid of the same dimensions by applying a function to each element.
+    ///
+    /// # Type Parameters
+    /// * `U` - The type of the elements in the new grid.
+    /// * `F` - The type of the function to apply to each element.
+    ///
+    /// # Arguments
+    /// * `func` - The function to apply to each element. It takes the `Coordinate` and a reference to the element.
+    ///
+    /// # Returns
+    /// An `UnsizedGrid` holding the mapped elements.
+    fn map<U, F>(&self, mut func: F) -> UnsizedGrid<U>
+    where
+        F: FnMut(Coordinate, &T) -> U,
+        Self: Sized,
+    {
+        let mut matrix = Vec::with_capacity(self.num_rows());
+        for row in self.iter() {
+            matrix.push(row.map(|(pos, e)| func(pos, e)).collect::<Vec<U>>());
+        }
+
+        UnsizedGrid::new(matrix)
+    }
+
+    /// Renders the grid into a multi-line string, mapping each element to a single character.
+    ///
+    /// # Type Parameters
+    /// * `F` - The type of the function mapping elements to characters.
+    ///
+    /// # Arguments
+    /// * `func` - Takes the `Coordinate` and a reference to the element and returns the character to draw.
+    ///
+    /// # Returns
+    /// A `String` with one line per row, separated by `'\n'` (no trailing newline).
+    fn render_with<F>(&self, func: F) -> String
+    where
+        F: Fn(Coordinate, &T) -> char,
+        Self: Sized,
+    {
+        let mut rendered = String::with_capacity(self.num_rows() * (self.num_cols() + 1));
+        for (i, row) in self.iter().enumerate() {
+            if i > 0 {
+                rendered.push('\n');
+            }
+            rendered.extend(row.map(|(pos, e)| func(pos, e)));
+        }
+
+        rendered
+    }
+
+    /// Applies a function to each element in the grid.
+    ///
+    /// Elements are visited in row-major order: left to right along row `0`,
+    /// then along row `1`, and so on.
+    ///
+    /// # Type Parameters
     /// * `F` - The type of the function to apply to each element.
-    /// * `A` - The type of the result.
+    /// * `A` - The type of the result, initialised with `A::default()`.
     ///
     /// # Arguments
     /// * `func` - The function to apply to each element. It takes a `Coordinate`, a reference to the element, and a mutable reference to the result.
     ///
     /// # Returns
     /// The result of applying the function to each element in the grid.
-    fn foreach<F, A>(&self, func: F) -> A
+    fn foreach<F, A>(&self, mut func: F) -> A
     where
-        F: Fn(Coordinate, &T, &mut A),
+        F: FnMut(Coordinate, &T, &mut A),
         A: Default,
         Self: Sized,
     {
@@ -131,6 +287,119 @@ pub mod iterators {
                 None
             }
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let len = self.len();
+            (len, Some(len))
+        }
+    }
+
+    impl<G, T> ExactSizeIterator for GridIter<'_, G, T>
+    where
+        G: Grid<T>,
+    {
+        /// Returns the number of rows left.
+        fn len(&self) -> usize {
+            self.grid.num_rows().saturating_sub(self.row)
+        }
+    }
+
+    /// An iterator over the columns of a grid.
+    pub struct GridColIter<'a, G, T>
+    where
+        G: Grid<T>,
+        T: 'a,
+    {
+        grid: &'a G,
+        col: usize,
+        _marker: PhantomData<&'a T>,
+    }
+
+    impl<'a, G, T> GridColIter<'a, G, T>
+    where
+        G: Grid<T>,
+    {
+        /// Creates a new `GridColIter` for the given grid.
+        #[inline(always)]
+        pub fn new(grid: &'a G) -> Self {
+            Self {
+                grid,
+                col: 0,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<'a, G, T> Iterator for GridColIter<'a, G, T>
+    where
+        G: Grid<T>,
+    {
+        type Item = ColIter<'a, G, T>;
+
+        /// Advances the iterator and returns the next column iterator.
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.col < self.grid.num_cols() {
+                let col_iter = ColIter::new(self.grid, self.col);
+                self.col += 1;
+                Some(col_iter)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// An iterator over the elements of a column in a grid.
+    ///
+    /// # Type Parameters
+    /// * `'a` - The lifetime of the references to the grid and its elements.
+    /// * `G` - The type of the grid.
+    /// * `T` - The type of the elements in the column.
+    pub struct ColIter<'a, G, T>
+    where
+        G: Grid<T>,
+        T: 'a,
+    {
+        /// A reference to the grid being walked.
+        grid: &'a G,
+        /// The index of the current row.
+        row: usize,
+        /// The index of the column being walked.
+        col: usize,
+        /// A marker to indicate the lifetime of the column elements.
+        _marker: PhantomData<&'a T>,
+    }
+
+    impl<'a, G, T> ColIter<'a, G, T>
+    where
+        G: Grid<T>,
+    {
+        pub fn new(grid: &'a G, col: usize) -> Self {
+            Self {
+                grid,
+                row: 0,
+                col,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<'a, G, T> Iterator for ColIter<'a, G, T>
+    where
+        G: Grid<T>,
+    {
+        type Item = (Coordinate, &'a T);
+
+        /// Advances the iterator and returns the next element in the column.
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.col < self.grid.num_cols() && self.row < self.grid.num_rows() {
+                let coordinate = Coordinate::new(self.row as i32, self.col as i32);
+                let value = &self.grid.get_row(self.row)[self.col];
+                self.row += 1;
+                Some((coordinate, value))
+            } else {
+                None
+            }
+        }
     }
 
     /// An iterator over the elements of a row in a grid.
@@ -148,6 +417,8 @@ pub mod iterators {
         row: usize,
         /// The index of the current column.
         col: usize,
+        /// One past the index of the last column not yet yielded from the back.
+        end: usize,
         /// A marker to indicate the lifetime of the row elements.
         _marker: PhantomData<&'a T>,
     }
@@ -158,6 +429,7 @@ pub mod iterators {
                 row_item,
                 row,
                 col,
+                end: row_item.len(),
                 _marker: PhantomData,
             }
         }
@@ -168,7 +440,7 @@ pub mod iterators {
 
         /// Advances the iterator and returns the next element in the row.
         fn next(&mut self) -> Option<Self::Item> {
-            if self.col < self.row_item.len() {
+            if self.col < self.end {
                 let coordinate = Coordinate::new(self.row as i32, self.col as i32);
                 let value = &self.row_item[self.col];
                 self.col += 1;
@@ -177,6 +449,31 @@ pub mod iterators {
                 None
             }
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let len = self.len();
+            (len, Some(len))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for RowIter<'_, T> {
+        /// Returns the last element of the row that has not been yielded yet.
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.col < self.end {
+                self.end -= 1;
+                let coordinate = Coordinate::new(self.row as i32, self.end as i32);
+                Some((coordinate, &self.row_item[self.end]))
+            } else {
+                None
+            }
+        }
+    }
+
+    impl<T> ExactSizeIterator for RowIter<'_, T> {
+        /// Returns the number of elements left in the row.
+        fn len(&self) -> usize {
+            self.end.saturating_sub(self.col)
+        }
     }
 
     /// An iterator over the elements of a row in a grid.
@@ -216,3 +513,185 @@ pub mod iterators {
         }
     }
 }
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+    use crate::utils::grid::sized_grid::SizedGrid;
+    use crate::utils::grid::unsized_grid::UnsizedGrid;
+
+    #[test]
+    fn test_neighbours() {
+        let grid = UnsizedGrid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+        let corner = grid
+            .neighbours(Coordinate::new(0, 0))
+            .map(|(_, e)| *e)
+            .collect::<Vec<_>>();
+        assert_eq!(corner, vec![2, 4]);
+
+        let edge = grid
+            .neighbours(Coordinate::new(1, 2))
+            .map(|(_, e)| *e)
+            .collect::<Vec<_>>();
+        assert_eq!(edge, vec![3, 9, 5]);
+
+        let centre = grid.neighbours(Coordinate::new(1, 1)).collect::<Vec<_>>();
+        assert_eq!(centre.len(), 4);
+        assert_eq!(centre[0], (Coordinate::new(0, 1), &2));
+    }
+
+    #[test]
+    fn test_neighbours_8() {
+        let grid = SizedGrid::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        let corner = grid
+            .neighbours_8(Coordinate::new(2, 2))
+            .map(|(_, e)| *e)
+            .collect::<Vec<_>>();
+        assert_eq!(corner, vec![6, 8, 5]);
+
+        let edge = grid
+            .neighbours_8(Coordinate::new(0, 1))
+            .map(|(_, e)| *e)
+            .collect::<Vec<_>>();
+        assert_eq!(edge, vec![3, 6, 5, 4, 1]);
+
+        assert_eq!(grid.neighbours_8(Coordinate::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = UnsizedGrid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let parity = grid.map(|_, e| e % 2 == 0);
+
+        assert_eq!(parity.num_rows(), grid.num_rows());
+        assert_eq!(parity.num_cols(), grid.num_cols());
+        assert_eq!(parity.get(&Coordinate::new(0, 0)), Some(&false));
+        assert_eq!(parity.get(&Coordinate::new(1, 0)), Some(&true));
+        assert_eq!(parity.get(&Coordinate::new(1, 2)), Some(&true));
+
+        let positions = SizedGrid::new([[0; 2]; 2]).map(|pos, _| pos);
+        assert_eq!(
+            positions.get(&Coordinate::new(1, 0)),
+            Some(&Coordinate::new(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = UnsizedGrid::new(vec![
+            vec![1, 2, 9, 3, 4],
+            vec![5, 9, 9, 6, 7],
+            vec![9, 8, 9, 8, 9],
+        ]);
+
+        let left = grid.flood_fill(Coordinate::new(0, 0), |&e| e < 9);
+        assert_eq!(left.len(), 3);
+        assert!(!left.contains(&Coordinate::new(2, 1)));
+
+        let right = grid.flood_fill(Coordinate::new(0, 4), |&e| e < 9);
+        assert_eq!(right.len(), 5);
+        assert!(right.contains(&Coordinate::new(2, 3)));
+
+        assert!(grid
+            .flood_fill(Coordinate::new(0, 2), |&e| e < 9)
+            .is_empty());
+        assert!(grid
+            .flood_fill(Coordinate::new(5, 5), |&e| e < 9)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_render_with() {
+        let grid = SizedGrid::new([[true, false, true], [false, true, false]]);
+        let rendered = grid.render_with(|_, &lit| if lit { '#' } else { '.' });
+        assert_eq!(rendered, "#.#\n.#.");
+
+        let diagonal = grid.render_with(|pos, _| if pos.i == pos.j { '\\' } else { ' ' });
+        assert_eq!(diagonal, "\\  \n \\ ");
+    }
+
+    #[test]
+    fn test_row_iter() {
+        let grid = UnsizedGrid::new(vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]]);
+        let row = |i| grid.iter().nth(i).unwrap();
+
+        assert_eq!(
+            row(1).rev().collect::<Vec<_>>(),
+            vec![
+                (Coordinate::new(1, 3), &8),
+                (Coordinate::new(1, 2), &7),
+                (Coordinate::new(1, 1), &6),
+                (Coordinate::new(1, 0), &5),
+            ]
+        );
+
+        let mut first = row(0);
+        assert_eq!(first.len(), 4);
+        assert_eq!(first.next(), Some((Coordinate::new(0, 0), &1)));
+        assert_eq!(first.len(), 3);
+        assert_eq!(first.next_back(), Some((Coordinate::new(0, 3), &4)));
+        assert_eq!(first.len(), 2);
+        assert_eq!(first.next_back(), Some((Coordinate::new(0, 2), &3)));
+        assert_eq!(first.next(), Some((Coordinate::new(0, 1), &2)));
+        assert_eq!(first.len(), 0);
+        assert_eq!(first.next(), None);
+        assert_eq!(first.next_back(), None);
+    }
+
+    #[test]
+    fn test_grid_iter_len() {
+        let grid = SizedGrid::new([[1, 2], [3, 4], [5, 6]]);
+
+        let mut rows = grid.iter();
+        assert_eq!(rows.len(), grid.num_rows());
+        rows.next();
+        assert_eq!(rows.len(), 2);
+        rows.by_ref().for_each(drop);
+        assert_eq!(rows.len(), 0);
+
+        let cells = UnsizedGrid::new(vec![vec![0; 4]; 5])
+            .iter()
+            .map(|row| row.len())
+            .collect::<Vec<_>>();
+        assert_eq!(cells, vec![4; 5]);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let unsized_grid = UnsizedGrid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let mut cells = 0;
+        for row in &unsized_grid {
+            for (position, &value) in row {
+                assert_eq!(unsized_grid.get(&position), Some(&value));
+                cells += 1;
+            }
+        }
+        assert_eq!(cells, 6);
+
+        let sized_grid = SizedGrid::new([[0u8; 4]; 3]);
+        let mut cells = 0;
+        for row in &sized_grid {
+            cells += row.count();
+        }
+        assert_eq!(cells, 12);
+    }
+
+    #[test]
+    fn test_col_iter() {
+        let grid = UnsizedGrid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(
+            grid.col_iter(1).collect::<Vec<_>>(),
+            vec![(Coordinate::new(0, 1), &2), (Coordinate::new(1, 1), &5)]
+        );
+        assert_eq!(grid.col_iter(3).count(), 0);
+
+        let columns = grid
+            .cols()
+            .map(|col| col.map(|(_, e)| *e).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::utils::coordinate_system::Coordinate;
-use crate::utils::grid::iterators::{GridIter, RowIterMut};
+use crate::utils::grid::iterators::{GridIter, RowIter, RowIterMut};
 use crate::utils::grid::{Grid, GridMut};
 use std::fmt::{Debug, Formatter};
 use std::iter::Enumerate;
@@ -52,6 +52,55 @@ impl<T, const ROW: usize, const COL: usize> SizedGrid<T, ROW, COL> {
         Self { matrix: grid }
     }
 
+    /// Creates a new `SizedGrid` by calling `f` with the coordinate of every cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Returns the element of a cell, called once per cell in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// A new `SizedGrid` instance.
+    #[allow(dead_code)]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(Coordinate) -> T,
+    {
+        Self::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| f(Coordinate::new(i as i32, j as i32)))
+        }))
+    }
+
+    /// Rotates the grid 90 degrees clockwise.
+    ///
+    /// # Returns
+    ///
+    /// A new `COL x ROW` `SizedGrid` where the element at `(r, c)` is moved to `(c, ROW - 1 - r)`.
+    #[allow(dead_code)]
+    pub fn rotate_cw(self) -> SizedGrid<T, COL, ROW>
+    where
+        T: Copy,
+    {
+        SizedGrid::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self.matrix[ROW - 1 - j][i])
+        }))
+    }
+
+    /// Rotates the grid 90 degrees counter-clockwise.
+    ///
+    /// # Returns
+    ///
+    /// A new `COL x ROW` `SizedGrid` where the element at `(r, c)` is moved to `(COL - 1 - c, r)`.
+    #[allow(dead_code)]
+    pub fn rotate_ccw(self) -> SizedGrid<T, COL, ROW>
+    where
+        T: Copy,
+    {
+        SizedGrid::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self.matrix[j][COL - 1 - i])
+        }))
+    }
+
     /// Returns the number of rows in the grid.
     ///
     /// # Returns
@@ -102,7 +151,7 @@ impl<T, const ROW: usize, const COL: usize> SizedGrid<T, ROW, COL> {
     #[allow(dead_code)]
     #[inline(always)]
     pub fn get_mut(&mut self, position: &Coordinate) -> Option<&mut T> {
-        if self.is_valid_coordinate(&position) {
+        if self.is_valid_coordinate(position) {
             Some(&mut self.matrix[position.i as usize][position.j as usize])
         } else {
             None
@@ -139,6 +188,16 @@ impl<T: Debug, const ROW: usize, const COL: usize> Debug for SizedGrid<T, ROW, C
     }
 }
 
+impl<'a, T, const ROW: usize, const COL: usize> IntoIterator for &'a SizedGrid<T, ROW, COL> {
+    type Item = RowIter<'a, T>;
+    type IntoIter = GridIter<'a, SizedGrid<T, ROW, COL>, T>;
+
+    /// Iterates over the rows of the grid, allowing `for row in &grid`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<T, const N: usize, const M: usize> Grid<T> for SizedGrid<T, N, M> {
     /// Returns the number of rows in the grid.
     fn num_rows(&self) -> usize {
@@ -269,3 +328,53 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod sized_grid_tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let grid = SizedGrid::new([[1, 2], [3, 4]]);
+
+        assert_eq!(grid.get(&Coordinate::new(0, 0)), Some(&1));
+        assert_eq!(grid.get(&Coordinate::new(1, 0)), Some(&3));
+        assert_eq!(grid.get(&Coordinate::new(1, 1)), Some(&4));
+        assert_eq!(grid.get(&Coordinate::new(2, 0)), None);
+        assert_eq!(grid.get(&Coordinate::new(0, 2)), None);
+        assert_eq!(grid.get(&Coordinate::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let grid = SizedGrid::<_, 3, 3>::from_fn(|c| c.i * 10 + c.j);
+        assert_eq!(grid.get(&Coordinate::new(0, 0)), Some(&0));
+        assert_eq!(grid.get(&Coordinate::new(0, 2)), Some(&2));
+        assert_eq!(grid.get(&Coordinate::new(2, 0)), Some(&20));
+        assert_eq!(grid.get(&Coordinate::new(2, 2)), Some(&22));
+        assert_eq!(grid.get(&Coordinate::new(1, 1)), Some(&11));
+
+        let mut calls = 0;
+        let order = SizedGrid::<_, 2, 3>::from_fn(|_| {
+            calls += 1;
+            calls
+        });
+        assert_eq!(order.matrix, [[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = SizedGrid::new([[1, 2, 3], [4, 5, 6]]);
+
+        let clockwise = SizedGrid::new([[1, 2, 3], [4, 5, 6]]).rotate_cw();
+        assert_eq!(clockwise.matrix, [[4, 1], [5, 2], [6, 3]]);
+        assert_eq!(clockwise.get(&Coordinate::new(0, 1)), Some(&1));
+        assert_eq!(clockwise.get(&Coordinate::new(2, 0)), Some(&6));
+
+        let counter_clockwise = SizedGrid::new([[1, 2, 3], [4, 5, 6]]).rotate_ccw();
+        assert_eq!(counter_clockwise.matrix, [[3, 6], [2, 5], [1, 4]]);
+        assert_eq!(counter_clockwise.get(&Coordinate::new(2, 0)), Some(&1));
+
+        assert_eq!(grid.rotate_cw().rotate_ccw().matrix, [[1, 2, 3], [4, 5, 6]]);
+    }
+}
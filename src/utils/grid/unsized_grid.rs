@@ -1,5 +1,5 @@
 use crate::utils::coordinate_system::Coordinate;
-use crate::utils::grid::iterators::{GridIter, RowIterMut};
+use crate::utils::grid::iterators::{GridIter, RowIter, RowIterMut};
 use crate::utils::grid::{Grid, GridMut};
 use std::fmt::Debug;
 use std::iter::Enumerate;
@@ -53,16 +53,50 @@ impl<T> UnsizedGrid<T> {
     /// # Returns
     ///
     /// A new `UnsizedGrid` instance with the specified dimensions and default values.
+    ///
+    /// # Panics
+    ///
+    /// If either `rows` or `cols` is zero.
     #[inline]
     pub fn new_with_size(rows: usize, cols: usize, default: T) -> Self
     where
         T: Clone,
     {
-        // Create a single row filled with the default value, to avoid multiple clones
-        // Clone the row for each additional row needed
+        assert!(rows > 0, "Grid must have at least one row");
+        assert!(cols > 0, "Grid must have at least one column");
         Self::new(vec![vec![default; cols]; rows])
     }
 
+    /// Creates a new `UnsizedGrid` with the specified number of rows and columns, calling `f`
+    /// with the coordinate of every cell to initialize it.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows in the grid.
+    /// * `cols` - The number of columns in the grid.
+    /// * `f` - Returns the element of a cell, called once per cell in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// A new `UnsizedGrid` instance with the specified dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If either `rows` or `cols` is zero.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Coordinate) -> T) -> Self {
+        assert!(rows > 0, "Grid must have at least one row");
+        assert!(cols > 0, "Grid must have at least one column");
+        Self::new(
+            (0..rows)
+                .map(|i| {
+                    (0..cols)
+                        .map(|j| f(Coordinate::new(i as i32, j as i32)))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
     /// Creates a new `UnsizedGrid` from a 2D vector.
     ///
     /// # Arguments
@@ -80,8 +114,8 @@ impl<T> UnsizedGrid<T> {
             .collect::<Vec<Box<[T]>>>()
             .into_boxed_slice();
 
-        assert!(grid.len() > 0);
-        assert!(grid[0].len() > 0);
+        assert!(!grid.is_empty());
+        assert!(!grid[0].is_empty());
 
         Self { matrix: grid }
     }
@@ -97,11 +131,38 @@ impl<T> UnsizedGrid<T> {
     /// A new `UnsizedGrid` instance.
     #[allow(dead_code)]
     pub fn from_box(grid: Box<[Box<[T]>]>) -> Self {
-        assert!(grid.len() > 0);
-        assert!(grid[0].len() > 0);
+        assert!(!grid.is_empty());
+        assert!(!grid[0].is_empty());
         Self { matrix: grid }
     }
 
+    /// Swaps the rows and columns of the grid.
+    ///
+    /// # Returns
+    ///
+    /// A new `num_cols x num_rows` `UnsizedGrid` where the element at `(i, j)` was at `(j, i)`.
+    ///
+    /// # Panics
+    ///
+    /// If the rows of the grid are not all the same length.
+    #[allow(dead_code)]
+    pub fn transpose(self) -> Self
+    where
+        T: Clone,
+    {
+        let num_cols = self.num_cols();
+        assert!(
+            self.matrix.iter().all(|row| row.len() == num_cols),
+            "Cannot transpose a grid whose rows differ in length"
+        );
+
+        Self::new(
+            (0..num_cols)
+                .map(|j| self.matrix.iter().map(|row| row[j].clone()).collect())
+                .collect(),
+        )
+    }
+
     /// Returns the number of rows in the grid.
     ///
     /// # Returns
@@ -177,6 +238,59 @@ impl<T> UnsizedGrid<T> {
     }
 }
 
+impl UnsizedGrid<u8> {
+    /// Creates a new `UnsizedGrid` from lines of single decimal digits, e.g. `"2199943210"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The rows of the grid. Surrounding whitespace on each line is ignored.
+    ///
+    /// # Returns
+    ///
+    /// The parsed grid, or an error describing the first non-digit character,
+    /// ragged row, or empty input encountered.
+    pub fn from_digit_lines(lines: &[String]) -> Result<Self, String> {
+        let grid = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                line.trim()
+                    .chars()
+                    .enumerate()
+                    .map(|(j, c)| {
+                        c.to_digit(10).map(|digit| digit as u8).ok_or_else(|| {
+                            format!("Invalid digit '{}' at row {} column {}", c, i, j)
+                        })
+                    })
+                    .collect::<Result<Box<[u8]>, String>>()
+            })
+            .collect::<Result<Box<[Box<[u8]>]>, String>>()?;
+
+        match grid.first() {
+            None => return Err("Cannot create a grid from no lines".to_string()),
+            Some(first) if first.is_empty() => {
+                return Err("Cannot create a grid from empty lines".to_string())
+            }
+            Some(first) => {
+                if let Some((i, row)) = grid
+                    .iter()
+                    .enumerate()
+                    .find(|(_, row)| row.len() != first.len())
+                {
+                    return Err(format!(
+                        "Row {} has {} columns, expected {}",
+                        i,
+                        row.len(),
+                        first.len()
+                    ));
+                }
+            }
+        }
+
+        Ok(Self::from_box(grid))
+    }
+}
+
 impl<T: Debug> Debug for UnsizedGrid<T> {
     /// Formats the grid using the given formatter.
     ///
@@ -200,6 +314,16 @@ impl<T: Debug> Debug for UnsizedGrid<T> {
     }
 }
 
+impl<'a, T> IntoIterator for &'a UnsizedGrid<T> {
+    type Item = RowIter<'a, T>;
+    type IntoIter = GridIter<'a, UnsizedGrid<T>, T>;
+
+    /// Iterates over the rows of the grid, allowing `for row in &grid`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<T> Grid<T> for UnsizedGrid<T> {
     /// Returns the number of rows in the grid.
     fn num_rows(&self) -> usize {
@@ -331,3 +455,94 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod unsized_grid_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_digit_lines() {
+        let lines = ["123", "456", "789"].map(String::from);
+        let grid = UnsizedGrid::from_digit_lines(&lines).unwrap();
+
+        assert_eq!(grid.num_rows(), 3);
+        assert_eq!(grid.num_cols(), 3);
+        assert_eq!(grid.get(&Coordinate::new(0, 0)), Some(&1));
+        assert_eq!(grid.get(&Coordinate::new(2, 1)), Some(&8));
+
+        let ragged = ["123", "45", "789"].map(String::from);
+        assert_eq!(
+            UnsizedGrid::from_digit_lines(&ragged).unwrap_err(),
+            "Row 1 has 2 columns, expected 3"
+        );
+
+        let not_digits = ["123", "4x6"].map(String::from);
+        assert!(UnsizedGrid::from_digit_lines(&not_digits).is_err());
+    }
+
+    #[test]
+    fn test_foreach() {
+        let grid = UnsizedGrid::new(vec![vec![1u8, 2, 3], vec![4, 5, 6]]);
+
+        let sum = grid.foreach(|_, e, acc: &mut u64| *acc += *e as u64);
+        assert_eq!(sum, 21);
+
+        let mut visited = vec![];
+        let _: () = grid.foreach(|pos, _, _| visited.push(pos));
+        assert_eq!(visited.first(), Some(&Coordinate::new(0, 0)));
+        assert_eq!(visited[1], Coordinate::new(0, 1));
+        assert_eq!(visited.last(), Some(&Coordinate::new(1, 2)));
+    }
+
+    #[test]
+    fn test_new_with_size() {
+        let grid = UnsizedGrid::new_with_size(3, 4, '.');
+
+        assert_eq!(grid.num_rows(), 3);
+        assert_eq!(grid.num_cols(), 4);
+        assert!(grid.matrix.iter().flatten().all(|&c| c == '.'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_with_size_empty() {
+        let _ = UnsizedGrid::new_with_size(0, 4, '.');
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let grid = UnsizedGrid::from_fn(3, 5, |c| (c.i + c.j) % 2 == 0);
+
+        assert_eq!(grid.num_rows(), 3);
+        assert_eq!(grid.num_cols(), 5);
+        assert_eq!(grid.get(&Coordinate::new(0, 0)), Some(&true));
+        assert_eq!(grid.get(&Coordinate::new(0, 1)), Some(&false));
+        assert_eq!(grid.get(&Coordinate::new(1, 0)), Some(&false));
+        assert_eq!(grid.get(&Coordinate::new(1, 3)), Some(&true));
+        assert_eq!(grid.get(&Coordinate::new(2, 4)), Some(&true));
+        assert_eq!(grid.get(&Coordinate::new(3, 0)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_fn_empty() {
+        let _ = UnsizedGrid::from_fn(2, 0, |_| 0);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = UnsizedGrid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let transposed = grid.transpose();
+
+        assert_eq!(transposed.num_rows(), 3);
+        assert_eq!(transposed.num_cols(), 2);
+        assert_eq!(transposed.get(&Coordinate::new(2, 0)), Some(&3));
+        assert_eq!(transposed.get(&Coordinate::new(0, 1)), Some(&4));
+
+        let round_trip = transposed.transpose();
+        assert_eq!(
+            round_trip.matrix,
+            UnsizedGrid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).matrix
+        );
+    }
+}
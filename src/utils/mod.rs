@@ -1,4 +1,6 @@
 pub mod coordinate_system;
+pub mod counter;
 pub mod day_setup;
 pub mod graph;
 pub mod grid;
+pub mod pathfinding;
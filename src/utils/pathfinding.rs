@@ -0,0 +1,231 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Finds the cheapest path from `start` to the first node satisfying `is_goal` using Dijkstra's
+/// algorithm.
+///
+/// Unlike [`Graph::shortest_path`](crate::utils::graph::Graph::shortest_path), the graph does not
+/// have to be built up front: `neighbours` is called lazily on every node that is settled, which
+/// makes it usable on grids and other implicit graphs.
+///
+/// # Arguments
+///
+/// * `start` - The node to start from.
+/// * `neighbours` - Returns the successors of a node along with the (non-negative) cost of moving
+///   to each of them.
+/// * `is_goal` - Returns whether a node is a destination.
+///
+/// # Returns
+///
+/// The total cost of the cheapest path and the path itself, from `start` up to and including the
+/// goal, or `None` if no goal is reachable.
+#[allow(dead_code)]
+pub fn dijkstra<N, FN, IN>(
+    start: N,
+    mut neighbours: FN,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<(u64, Vec<N>)>
+where
+    N: Clone + Eq + Hash + Ord,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, u64)>,
+{
+    let mut min_cost = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors = HashMap::<N, N>::new();
+    let mut heap = BinaryHeap::from([Reverse((0, start))]);
+
+    while let Some(Reverse((cost, node))) = heap.pop() {
+        if is_goal(&node) {
+            return Some((cost, reconstruct_path(&predecessors, node)));
+        }
+        if cost > min_cost[&node] {
+            continue;
+        }
+
+        for (neighbour, weight) in neighbours(&node) {
+            let new_cost = cost + weight;
+            if min_cost
+                .get(&neighbour)
+                .is_none_or(|&known_cost| new_cost < known_cost)
+            {
+                min_cost.insert(neighbour.clone(), new_cost);
+                predecessors.insert(neighbour.clone(), node.clone());
+                heap.push(Reverse((new_cost, neighbour)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the path with the fewest steps from `start` to the first node satisfying `is_goal` using a
+/// breadth-first search.
+///
+/// Like [`dijkstra`], `neighbours` is called lazily, but every move counts as a single step.
+///
+/// # Arguments
+///
+/// * `start` - The node to start from.
+/// * `neighbours` - Returns the successors of a node.
+/// * `is_goal` - Returns whether a node is a destination.
+///
+/// # Returns
+///
+/// The shortest path from `start` up to and including the goal, or `None` if no goal is reachable.
+#[allow(dead_code)]
+pub fn bfs<N, FN, IN>(start: N, mut neighbours: FN, is_goal: impl Fn(&N) -> bool) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::from([start.clone()]);
+    let mut predecessors = HashMap::<N, N>::new();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct_path(&predecessors, node));
+        }
+
+        for neighbour in neighbours(&node) {
+            if visited.insert(neighbour.clone()) {
+                predecessors.insert(neighbour.clone(), node.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    None
+}
+
+/// Walks the `predecessors` back from `end` and returns the path in the order it was travelled.
+fn reconstruct_path<N>(predecessors: &HashMap<N, N>, end: N) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut path = vec![end];
+    while let Some(previous) = predecessors.get(path.last().unwrap()) {
+        path.push(previous.clone());
+    }
+    path.reverse();
+
+    path
+}
+
+#[cfg(test)]
+mod pathfinding_tests {
+    use super::*;
+    use crate::utils::coordinate_system::Coordinate;
+
+    #[test]
+    fn test_dijkstra_grid() {
+        // Entering a cell costs its value, so the cheapest path goes around the 9s
+        let grid = [[1, 9, 1], [1, 9, 1], [1, 1, 1]];
+        let end = Coordinate::new(0, 2);
+
+        let neighbours = |coordinate: &Coordinate| {
+            coordinate
+                .neighbours()
+                .filter_map(|neighbour| {
+                    let row = grid.get(usize::try_from(neighbour.i).ok()?)?;
+                    let cell = row.get(usize::try_from(neighbour.j).ok()?)?;
+                    Some((neighbour, *cell))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let (cost, path) = dijkstra(Coordinate::new(0, 0), neighbours, |c| *c == end).unwrap();
+        assert_eq!(cost, 6);
+        assert_eq!(
+            path,
+            [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2)]
+                .map(|(i, j)| Coordinate::new(i, j))
+        );
+
+        let (cost, path) = dijkstra(end, neighbours, |c| *c == end).unwrap();
+        assert_eq!((cost, path), (0, vec![end]));
+    }
+
+    #[test]
+    fn test_dijkstra_weighted_graph() {
+        // A -1-> B -10-> D
+        // A -4-> C  -2-> D
+        // E is unreachable
+        let edges = HashMap::from([
+            ("A", vec![("B", 1), ("C", 4)]),
+            ("B", vec![("D", 10)]),
+            ("C", vec![("D", 2)]),
+            ("E", vec![("A", 1)]),
+        ]);
+        let neighbours = |node: &&str| edges.get(node).cloned().unwrap_or_default();
+
+        assert_eq!(
+            dijkstra("A", neighbours, |node| *node == "D"),
+            Some((6, vec!["A", "C", "D"]))
+        );
+        assert_eq!(
+            dijkstra("A", neighbours, |node| *node == "B"),
+            Some((1, vec!["A", "B"]))
+        );
+        assert_eq!(dijkstra("A", neighbours, |node| *node == "E"), None);
+    }
+
+    /// Yields the neighbours of a coordinate inside a `rows` x `cols` grid that are not walls.
+    fn open_neighbours(
+        rows: i32,
+        cols: i32,
+        walls: &[Coordinate],
+    ) -> impl Fn(&Coordinate) -> Vec<Coordinate> + '_ {
+        move |coordinate: &Coordinate| {
+            coordinate
+                .neighbours()
+                .filter(|n| (0..rows).contains(&n.i) && (0..cols).contains(&n.j))
+                .filter(|n| !walls.contains(n))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_bfs_unobstructed() {
+        let start = Coordinate::new(1, 2);
+        for end in [(0, 0), (4, 4), (1, 2), (3, 0)].map(|(i, j)| Coordinate::new(i, j)) {
+            let path = bfs(start, open_neighbours(5, 5, &[]), |c| *c == end).unwrap();
+
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&end));
+            assert_eq!(
+                path.len() as i32 - 1,
+                (end - start).manhattan_distance(),
+                "{end:?}"
+            );
+            assert!(path
+                .windows(2)
+                .all(|step| (step[1] - step[0]).manhattan_distance() == 1));
+        }
+    }
+
+    #[test]
+    fn test_bfs_obstructed() {
+        // .#.
+        // .#.
+        // ...
+        let walls = [Coordinate::new(0, 1), Coordinate::new(1, 1)];
+        let start = Coordinate::new(0, 0);
+        let end = Coordinate::new(0, 2);
+
+        let path = bfs(start, open_neighbours(3, 3, &walls), |c| *c == end).unwrap();
+        assert_eq!(path.len() - 1, 6);
+
+        let walls = [
+            Coordinate::new(0, 1),
+            Coordinate::new(1, 1),
+            Coordinate::new(2, 1),
+        ];
+        assert_eq!(
+            bfs(start, open_neighbours(3, 3, &walls), |c| *c == end),
+            None
+        );
+    }
+}